@@ -1,8 +1,11 @@
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
 use criterion::{
-	criterion_group, criterion_main, AxisScale, BatchSize, BenchmarkId, Criterion,
-	PlotConfiguration, Throughput,
+	criterion_group, criterion_main, measurement::WallTime, AxisScale, BatchSize, BenchmarkGroup,
+	BenchmarkId, Criterion, PlotConfiguration, Throughput,
 };
-use num3::gen_matrix;
+use num3::{gen_matrix, BandMatrix, Vector};
+use num_traits::{Num, One, ParseFloatError, Zero};
 
 fn bench(c: &mut Criterion) {
 	let mut group = c.benchmark_group("LU decomposition");
@@ -41,5 +44,158 @@ fn bench(c: &mut Criterion) {
 	group.finish();
 }
 
-criterion_group!(benches, bench);
+/// An `f64` which the `simd` feature doesn't recognize, so the kernels fall
+/// back to their scalar loops. Without the feature, both variants of each
+/// benchmark below are scalar.
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Scalar(f64);
+
+macro_rules! impl_scalar_op {
+	($trait:ident, $method:ident) => {
+		impl $trait for Scalar {
+			type Output = Self;
+
+			fn $method(self, rhs: Self) -> Self {
+				Scalar(self.0.$method(rhs.0))
+			}
+		}
+	};
+}
+
+impl_scalar_op!(Add, add);
+impl_scalar_op!(Sub, sub);
+impl_scalar_op!(Mul, mul);
+impl_scalar_op!(Div, div);
+impl_scalar_op!(Rem, rem);
+
+impl Zero for Scalar {
+	fn zero() -> Self {
+		Scalar(0.0)
+	}
+
+	fn is_zero(&self) -> bool {
+		self.0 == 0.0
+	}
+}
+
+impl One for Scalar {
+	fn one() -> Self {
+		Scalar(1.0)
+	}
+}
+
+impl Num for Scalar {
+	type FromStrRadixErr = ParseFloatError;
+
+	fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+		f64::from_str_radix(str, radix).map(Scalar)
+	}
+}
+
+impl From<f64> for Scalar {
+	fn from(value: f64) -> Self {
+		Scalar(value)
+	}
+}
+
+/// Generate a diagonally dominant matrix with wide bands, for which
+/// `lu_decompose` uses the blocked algorithm or the SIMD kernels
+fn gen_wide_matrix<E: Num + Copy + From<f64>, const L: usize, const U: usize>(
+	n: usize,
+) -> BandMatrix<E, L, U> {
+	BandMatrix::from_fn(n, |i, j| {
+		if i == j {
+			E::from((L + U + 1) as f64)
+		} else {
+			E::from(1.0 / (i + j) as f64)
+		}
+	})
+}
+
+/// Benchmark `lu_decompose` of a wide band matrix with and without SIMD
+fn bench_wide_lu<const L: usize, const U: usize>(
+	group: &mut BenchmarkGroup<'_, WallTime>,
+	size: usize,
+) {
+	group.bench_with_input(
+		BenchmarkId::new(format!("L = {L}, U = {U}"), size),
+		&size,
+		|b, &size| {
+			b.iter_batched(
+				|| gen_wide_matrix::<f64, L, U>(size),
+				|matrix| matrix.lu_decompose(),
+				BatchSize::SmallInput,
+			);
+		},
+	);
+
+	group.bench_with_input(
+		BenchmarkId::new(format!("L = {L}, U = {U}, scalar"), size),
+		&size,
+		|b, &size| {
+			b.iter_batched(
+				|| gen_wide_matrix::<Scalar, L, U>(size),
+				|matrix| matrix.lu_decompose(),
+				BatchSize::SmallInput,
+			);
+		},
+	);
+}
+
+fn bench_wide(c: &mut Criterion) {
+	let mut group = c.benchmark_group("Wide band LU decomposition");
+	group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+
+	for exp in 6..=16 {
+		let size = 2usize.pow(exp);
+		group.throughput(Throughput::Elements(size as u64));
+
+		bench_wide_lu::<8, 8>(&mut group, size);
+		bench_wide_lu::<32, 32>(&mut group, size);
+		bench_wide_lu::<128, 128>(&mut group, size);
+	}
+	group.finish();
+}
+
+/// Benchmark one Jacobi and one Gauss-Seidel iteration on a wide band matrix,
+/// with `E = Scalar` giving the variant without SIMD
+fn bench_wide_iteration<E, const L: usize, const U: usize>(
+	group: &mut BenchmarkGroup<'_, WallTime>,
+	size: usize,
+	variant: &str,
+) where
+	E: Num + Copy + From<f64> + Send + Sync + 'static,
+{
+	let matrix = gen_wide_matrix::<E, L, U>(size);
+	let x = Vector::from_iter((1..=size).map(|i| E::from(i as f64)));
+	let b = Vector::from_iter((1..=size).map(|_| E::from(1.0)));
+
+	group.bench_function(
+		BenchmarkId::new(format!("Jacobi, L = {L}, U = {U}{variant}"), size),
+		|bencher| bencher.iter(|| matrix.jacobi_iteration(&x, &b)),
+	);
+
+	group.bench_function(
+		BenchmarkId::new(format!("Gauss-Seidel, L = {L}, U = {U}{variant}"), size),
+		|bencher| bencher.iter(|| matrix.gauss_seidel_iteration(&x, &b)),
+	);
+}
+
+fn bench_wide_iterations(c: &mut Criterion) {
+	let mut group = c.benchmark_group("Wide band iterations");
+	group.plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+
+	for exp in 6..=16 {
+		let size = 2usize.pow(exp);
+		group.throughput(Throughput::Elements(size as u64));
+
+		bench_wide_iteration::<f64, 8, 8>(&mut group, size, "");
+		bench_wide_iteration::<Scalar, 8, 8>(&mut group, size, ", scalar");
+		bench_wide_iteration::<f64, 32, 32>(&mut group, size, "");
+		bench_wide_iteration::<Scalar, 32, 32>(&mut group, size, ", scalar");
+	}
+	group.finish();
+}
+
+criterion_group!(benches, bench, bench_wide, bench_wide_iterations);
 criterion_main!(benches);
@@ -1,31 +1,16 @@
-mod matrix;
-mod vector;
-
-use matrix::BandMatrix;
 use nalgebra::{DMatrix, DVector};
-use vector::Vector;
+
+pub use band::*;
 
 /// Generate the matrix from NUM3
 pub fn gen_matrix(n: usize) -> BandMatrix<f64, 1, 2> {
-	let mut res = BandMatrix::new(n);
-
-	for i in 1..=n - 1 {
-		res[(i + 1, i)] = 0.2;
-	}
-
-	for i in 1..=n {
-		res[(i, i)] = 1.2;
-	}
-
-	for i in 1..=n - 1 {
-		res[(i, i + 1)] = 0.1 / i as f64;
-	}
-
-	for i in 1..=n - 2 {
-		res[(i, i + 2)] = 0.15 / (i as f64).powi(2);
-	}
-
-	res
+	// Match on the diagonal $j - i$, shifted by L = 1
+	BandMatrix::from_fn(n, |i, j| match j + 1 - i {
+		0 => 0.2,
+		1 => 1.2,
+		2 => 0.1 / i as f64,
+		_ => 0.15 / (i as f64).powi(2),
+	})
 }
 
 /// Generate the vector from NUM3
@@ -37,7 +22,7 @@ pub fn gen_vector(n: usize) -> Vector<f64> {
 pub fn gen_matrix_nalgebra(n: usize) -> DMatrix<f64> {
 	let mut res = DMatrix::zeros(n, n);
 
-	for i in 0..n - 1 {
+	for i in 0..n.saturating_sub(1) {
 		res[(i + 1, i)] = 0.2;
 	}
 
@@ -45,11 +30,11 @@ pub fn gen_matrix_nalgebra(n: usize) -> DMatrix<f64> {
 		res[(i, i)] = 1.2;
 	}
 
-	for i in 0..n - 1 {
+	for i in 0..n.saturating_sub(1) {
 		res[(i, i + 1)] = 0.1 / (i + 1) as f64;
 	}
 
-	for i in 0..n - 2 {
+	for i in 0..n.saturating_sub(2) {
 		res[(i, i + 2)] = 0.15 / ((i + 1) as f64).powi(2);
 	}
 
@@ -26,28 +26,28 @@ fn main() {
 	let nal = LU::new(nal);
 	assert!((mat.det() - nal.determinant()).abs() < f64::EPSILON);
 
-	for i in 1..=124 {
-		for j in i..=124 {
-			assert!((mat[(i, j)] - nal.u()[(i - 1, j - 1)]).abs() < f64::EPSILON);
+	for i in 0..124 {
+		for j in i..124 {
+			assert!((mat.at0((i, j)) - nal.u()[(i, j)]).abs() < f64::EPSILON);
 		}
 
-		for j in 1..i {
-			assert!((mat[(i, j)] - nal.l()[(i - 1, j - 1)]).abs() < f64::EPSILON);
+		for j in 0..i {
+			assert!((mat.at0((i, j)) - nal.l()[(i, j)]).abs() < f64::EPSILON);
 		}
 	}
 
 	let x = gen_vector(124);
 	let xnal = gen_vector_nalgebra(124);
 
-	for i in 1..=124 {
-		assert!((x[i] - xnal[i - 1]).abs() < f64::EPSILON);
+	for i in 0..124 {
+		assert!((x.at0(i) - xnal[i]).abs() < f64::EPSILON);
 	}
 
 	let y = mat.solve(&x);
 	let ynal = nal.solve(&xnal).unwrap();
 
-	for i in 1..=124 {
-		assert!((y[i] - ynal[i - 1]).abs() < f64::EPSILON * 124.0);
+	for i in 0..124 {
+		assert!((y.at0(i) - ynal[i]).abs() < f64::EPSILON * 124.0);
 	}
 
 	println!("y₁₂₄ = {y}");
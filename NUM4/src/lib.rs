@@ -1,62 +1,52 @@
-mod matrix;
-mod vector;
+use std::iter;
 
-use std::{iter, ops::Add};
-
-use matrix::BandMatrix;
 use nalgebra::{DMatrix, DVector};
-use num_traits::Num;
-use vector::Vector;
+use num_traits::{real::Real, Num};
+
+pub use band::*;
 
-/// Solve $(A' + uv^T)x = b$ for $x$, where $u = v = [ 1 1 ... 1 ]^T$
-pub fn solve<E: Num + Copy, const L: usize, const U: usize>(
+/// Solve $(A' + uv^T)x = b$ for $x$, where $u = v = [ 1 1 ... 1 ]^T$. The
+/// systems $A'y = b$ and $A'z = u$ are independent, so they are solved in
+/// parallel if the `rayon` feature is enabled.
+pub fn solve<E: Num + Copy + MaybeSync + MaybeSimd, const L: usize, const U: usize>(
 	a_prime: BandMatrix<E, L, U>,
 	b: Vector<E>,
 ) -> Vector<E> {
 	assert_eq!(a_prime.n(), b.n(), "A''s height must equal b's size");
 
-	// $A' = LU$
-	let lu = a_prime.lu_decompose();
-
-	// $LUy = b$
-	let y = lu.solve(&b);
-
-	// $LUz = u$
-	let z = lu.solve(&Vector::from_iter(iter::repeat(E::one()).take(b.n())));
-
-	// $zv^Ty = (\sum_{i=1}^n y_i)z$
-	let vy = y.iter().copied().reduce(Add::add).unwrap();
+	let ones = Vector::from_iter(iter::repeat_n(E::one(), b.n()));
+	ShermanMorrison::new_solving(a_prime, ones.clone(), ones, b).1
+}
 
-	// $v^Tz = \sum_{i=1}^n z_i$
-	let vz = z.iter().copied().reduce(Add::add).unwrap();
+/// Solve $(A' + uv^T)x = b$ for $x$, where $u = v = [ 1 1 ... 1 ]^T$, like
+/// [`solve`], but return an error instead of an inaccurate result if the
+/// Sherman-Morrison formula breaks down (see [`ShermanMorrison::try_new`])
+pub fn try_solve<E: Real + MaybeSimd, const L: usize, const U: usize>(
+	a_prime: BandMatrix<E, L, U>,
+	b: Vector<E>,
+	tolerance: E,
+) -> Result<Vector<E>, ShermanMorrisonError<E>> {
+	assert_eq!(a_prime.n(), b.n(), "A''s height must equal b's size");
 
-	// $x = y - \frac{zv^Ty}{1 + v^Tz}$
-	y - Vector::from_iter(z.into_iter().map(|z| z * vy)) / (E::one() + vz)
+	let ones = Vector::from_iter(iter::repeat_n(E::one(), b.n()));
+	let mut x = b;
+	ShermanMorrison::try_new(a_prime, ones.clone(), ones, tolerance)?.solve_in_place(&mut x);
+	Ok(x)
 }
 
 /// Generate the band matrix A' from NUM4
 pub fn gen_matrix(n: usize) -> BandMatrix<f64, 0, 1> {
-	let mut res = BandMatrix::new(n);
-
-	for i in 1..=n {
-		res[(i, i)] = 12.0 - 1.0;
-	}
-
-	for i in 1..=n - 1 {
-		res[(i, i + 1)] = 8.0 - 1.0;
-	}
-
-	res
+	BandMatrix::from_constant_diagonals(n, &[12.0 - 1.0, 8.0 - 1.0]).unwrap()
 }
 
 /// Generate the vector u = vᵀ from NUM4
 pub fn gen_u(n: usize) -> Vector<f64> {
-	Vector::from_iter(iter::repeat(1.0).take(n))
+	Vector::from_iter(iter::repeat_n(1.0, n))
 }
 
 /// Generate the vector b from NUM4
 pub fn gen_b(n: usize) -> Vector<f64> {
-	Vector::from_iter(iter::repeat(5.0).take(n))
+	Vector::from_iter(iter::repeat_n(5.0, n))
 }
 
 /// Generate the matrix A from NUM4
@@ -68,7 +58,7 @@ pub fn gen_matrix_nalgebra(n: usize) -> DMatrix<f64> {
 		res[(i, i)] = 12.0;
 	}
 
-	for i in 0..n - 1 {
+	for i in 0..n.saturating_sub(1) {
 		res[(i, i + 1)] = 8.0;
 	}
 
@@ -77,5 +67,40 @@ pub fn gen_matrix_nalgebra(n: usize) -> DMatrix<f64> {
 
 /// Generate the vector b from NUM4
 pub fn gen_b_nalgebra(n: usize) -> DVector<f64> {
-	DVector::from(iter::repeat(5.0).take(n).collect::<Vec<_>>())
+	DVector::from(iter::repeat_n(5.0, n).collect::<Vec<_>>())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn try_solve_equals_solve() {
+		let tolerance = f64::EPSILON.sqrt();
+
+		// The NUM4 system is well-conditioned
+		let x = try_solve(gen_matrix(80), gen_b(80), tolerance).unwrap();
+		assert_eq!(x, solve(gen_matrix(80), gen_b(80)));
+	}
+
+	#[test]
+	fn low_rank_with_ones_equals_solve() {
+		let ones = [gen_u(80)];
+		let x = solve(gen_matrix(80), gen_b(80));
+		let y = solve_low_rank(gen_matrix(80), &ones, &ones, gen_b(80)).unwrap();
+
+		for i in 1..=80 {
+			assert!((x[i] - y[i]).abs() < 1e-14);
+		}
+	}
+
+	#[test]
+	fn tiny_systems() {
+		// $(11 + 1)x = 5$
+		let x = solve(gen_matrix(1), gen_b(1));
+		assert!((x[1] - 5.0 / 12.0).abs() < 1e-15);
+
+		assert_eq!(gen_matrix(0).n(), 0);
+		assert_eq!(gen_matrix_nalgebra(1).nrows(), 1);
+	}
 }
@@ -1,5 +1,5 @@
 use nalgebra::LU;
-use num4::{gen_b, gen_b_nalgebra, gen_matrix, gen_matrix_nalgebra, solve};
+use num4::{gen_b, gen_b_nalgebra, gen_matrix, gen_matrix_nalgebra, gen_u, solve, BandPlusRankOne};
 
 fn main() {
 	let mat = gen_matrix(10);
@@ -13,28 +13,32 @@ fn main() {
 		.solve(&gen_b_nalgebra(10))
 		.unwrap();
 
-	for i in 1..=10 {
-		assert!((x[i] - xnal[i - 1]).abs() < f64::EPSILON);
+	for i in 0..10 {
+		assert!((x.at0(i) - xnal[i]).abs() < f64::EPSILON);
 	}
 
 	let nal = gen_matrix_nalgebra(10);
 	println!("Aₙₐₗ ₁₀ = {nal:.1}");
 
+	let det = BandPlusRankOne::new(gen_matrix(10), gen_u(10), gen_u(10)).det();
+	println!("det A₁₀ = {det:.5}");
+	assert!((det - nal.determinant()).abs() < det.abs() * f64::EPSILON * 10.0);
+
 	let mat = gen_matrix(80);
 	let nal = gen_matrix_nalgebra(80);
 
 	let b = gen_b(80);
 	let bnal = gen_b_nalgebra(80);
 
-	for i in 1..=80 {
-		assert!((b[i] - bnal[i - 1]).abs() < f64::EPSILON);
+	for i in 0..80 {
+		assert!((b.at0(i) - bnal[i]).abs() < f64::EPSILON);
 	}
 
 	let x = solve(mat, b);
 	let xnal = LU::new(nal).solve(&bnal).unwrap();
 
-	for i in 1..=80 {
-		assert!((x[i] - xnal[i - 1]).abs() < f64::EPSILON * 80.0);
+	for i in 0..80 {
+		assert!((x.at0(i) - xnal[i]).abs() < f64::EPSILON * 80.0);
 	}
 
 	println!("x₈₀ = {x:.8}");
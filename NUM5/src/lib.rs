@@ -1,34 +1,16 @@
-mod matrix;
-mod vector;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-pub use matrix::BandMatrix;
-pub use vector::Vector;
+pub use band::*;
 
 /// Generate the band matrix A from NUM5
 pub fn gen_matrix(n: usize) -> BandMatrix<f64, 2, 2> {
-	let mut res = BandMatrix::new(n);
-
-	for i in 1..=n {
-		res[(i, i)] = 3.0;
-	}
-
-	for i in 1..=n - 1 {
-		res[(i, i + 1)] = 1.0;
-	}
-
-	for i in 1..=n - 2 {
-		res[(i, i + 2)] = 0.15;
-	}
-
-	for i in 1..=n - 1 {
-		res[(i + 1, i)] = 1.0;
-	}
-
-	for i in 1..=n - 2 {
-		res[(i + 2, i)] = 0.15;
-	}
+	BandMatrix::from_constant_diagonals(n, &[0.15, 1.0, 3.0, 1.0, 0.15]).unwrap()
+}
 
-	res
+/// Generate the band matrix A from NUM5 as a Toeplitz band matrix, which
+/// requires only `O(1)` storage space
+pub fn gen_toeplitz(n: usize) -> ToeplitzBand<f64, 2, 2> {
+	ToeplitzBand::new(n, &[0.15, 1.0, 3.0, 1.0, 0.15]).unwrap()
 }
 
 /// Generate the vector b from NUM5
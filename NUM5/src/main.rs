@@ -1,10 +1,16 @@
 use std::iter;
 
-use plotters::{coord::types::RangedCoordi32, prelude::*};
+use plotters::prelude::*;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
-use num5::{gen_b, gen_matrix, BandMatrix, Vector};
+use num5::{
+	gen_b, gen_matrix,
+	plot::{draw_errors, error_chart, ErrorChart},
+	Anderson, BandMatrix, Chebyshev, Identity, LinearOperator, Sor, Vector,
+};
 
-type Chart<'a, 'b> = ChartContext<'a, SVGBackend<'b>, Cartesian2d<RangedCoordi32, LogCoord<f64>>>;
+type Chart<'a, 'b> = ErrorChart<'a, SVGBackend<'b>>;
 
 fn superscript(n: usize) -> String {
 	const DIGITS: &[char] = &['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
@@ -56,45 +62,58 @@ fn main() {
 
 	println!("x₁₂₄ = {exact:.6}");
 
-	let xs = iter::repeat(())
-		.take(
-			pico_args::Arguments::from_env()
-				.opt_value_from_str("--starting-points")
-				.unwrap()
-				.unwrap_or(5),
-		)
-		.map(|_| Vector::from_iter(iter::repeat(()).take(124).map(|_| rand::random())))
-		.collect::<Vec<_>>();
+	let omega = mat.optimal_sor_omega(1000).unwrap();
+	println!("ω₁₂₄ = {omega:.6}");
+
+	let rho = mat.jacobi_spectral_radius(1000);
+	println!("ρ₁₂₄ = {rho:.6}");
+
+	let xs = iter::repeat_n(
+		(),
+		pico_args::Arguments::from_env()
+			.opt_value_from_str("--starting-points")
+			.unwrap()
+			.unwrap_or(5),
+	)
+	.map(|_| Vector::random(124))
+	.collect::<Vec<_>>();
 
 	let root = SVGBackend::new("./errors.svg", (1000, 500)).into_drawing_area();
 	root.fill(&WHITE).unwrap();
 
-	let mut chart = ChartBuilder::on(&root)
-		.set_label_area_size(LabelAreaPosition::Left, 60)
-		.set_label_area_size(LabelAreaPosition::Bottom, 60)
-		.build_cartesian_2d(0..81, (f32::EPSILON.into()..200.0).log_scale())
-		.unwrap();
-
-	chart
-		.configure_mesh()
-		.x_desc("k")
-		.y_desc("||x⁽ᵏ⁾ - x||")
-		.draw()
-		.unwrap();
+	let mut chart = error_chart(&root, 81, f32::EPSILON.into(), 200.0).unwrap();
 
 	plot_jacobi(&mut chart, &mat, &b, &exact, &xs);
 	plot_gauss_seidel(&mut chart, &mat, &b, &exact, &xs);
+	plot_sor(&mut chart, &mat, &b, &exact, &xs, omega);
+	plot_conjugate_gradient(&mut chart, &mat, &b, &exact, &xs);
+	plot_chebyshev_jacobi(&mut chart, &mat, &b, &exact, &xs, rho);
+	plot_anderson_gauss_seidel(&mut chart, &mat, &b, &exact, &xs);
 
 	chart
 		.configure_series_labels()
-		.border_style(&BLACK)
-		.background_style(&WHITE.mix(0.8))
+		.border_style(BLACK)
+		.background_style(WHITE.mix(0.8))
 		.draw()
 		.unwrap();
 
 	root.present().unwrap();
 }
 
+/// Compute `f(x)` for every starting point `x` (in parallel if the `rayon`
+/// feature is enabled)
+fn map_starting_points<T: Send>(
+	xs: &[Vector<f64>],
+	f: impl Fn(&Vector<f64>) -> T + Send + Sync,
+) -> Vec<T> {
+	#[cfg(feature = "rayon")]
+	let xs = xs.par_iter();
+	#[cfg(not(feature = "rayon"))]
+	let xs = xs.iter();
+
+	xs.map(f).collect()
+}
+
 fn plot_jacobi(
 	chart: &mut Chart<'_, '_>,
 	mat: &BandMatrix<f64, 2, 2>,
@@ -102,34 +121,27 @@ fn plot_jacobi(
 	exact: &Vector<f64>,
 	xs: &[Vector<f64>],
 ) {
-	for x in xs {
+	let errors = map_starting_points(xs, |x| {
 		let mut x = x.clone();
 
-		let data: Vec<_> = {
-			iter::once((&x - exact).norm())
-				.chain(iter::from_fn(|| {
-					x = mat.jacobi_iteration(&x, b);
-					let error = (&x - exact).norm();
-
-					if error < f32::EPSILON.into() {
-						None
-					} else {
-						Some(error)
-					}
-				}))
-				.collect()
-		};
-
-		let color = HSLColor((rand::random::<f64>() + 1.0) / 4.0, 0.9, 0.75);
-		chart
-			.draw_series(LineSeries::new(
-				(0..).zip(data.iter()).map(|(x, y)| (x, *y)),
-				color,
-			))
-			.unwrap()
-			.label("Jacobi")
-			.legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &color));
-	}
+		iter::once((&x - exact).norm())
+			.chain(iter::from_fn(|| {
+				x = mat.jacobi_iteration(&x, b);
+				let error = (&x - exact).norm();
+
+				if error < f32::EPSILON.into() {
+					None
+				} else {
+					Some(error)
+				}
+			}))
+			.collect::<Vec<_>>()
+	});
+
+	draw_errors(chart, &errors, "Jacobi", || {
+		HSLColor((rand::random::<f64>() + 1.0) / 4.0, 0.9, 0.75)
+	})
+	.unwrap();
 }
 
 fn plot_gauss_seidel(
@@ -139,32 +151,126 @@ fn plot_gauss_seidel(
 	exact: &Vector<f64>,
 	xs: &[Vector<f64>],
 ) {
-	for x in xs {
+	let errors = map_starting_points(xs, |x| {
 		let mut x = x.clone();
 
-		let data: Vec<_> = {
-			iter::once((&x - exact).norm())
-				.chain(iter::from_fn(|| {
-					x = mat.gauss_seidel_iteration(&x, b);
-					let error = (&x - exact).norm();
-
-					if error < f32::EPSILON.into() {
-						None
-					} else {
-						Some(error)
-					}
-				}))
-				.collect()
-		};
-
-		let color = HSLColor((rand::random::<f64>() + 3.0) / 4.0, 0.9, 0.75);
-		chart
-			.draw_series(LineSeries::new(
-				(0..).zip(data.iter()).map(|(x, y)| (x, *y)),
-				color,
-			))
-			.unwrap()
-			.label("Gauss-Seidel")
-			.legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &color));
-	}
+		iter::once((&x - exact).norm())
+			.chain(iter::from_fn(|| {
+				x = mat.gauss_seidel_iteration(&x, b);
+				let error = (&x - exact).norm();
+
+				if error < f32::EPSILON.into() {
+					None
+				} else {
+					Some(error)
+				}
+			}))
+			.collect::<Vec<_>>()
+	});
+
+	draw_errors(chart, &errors, "Gauss-Seidel", || {
+		HSLColor((rand::random::<f64>() + 3.0) / 4.0, 0.9, 0.75)
+	})
+	.unwrap();
+}
+
+fn plot_sor(
+	chart: &mut Chart<'_, '_>,
+	mat: &BandMatrix<f64, 2, 2>,
+	b: &Vector<f64>,
+	exact: &Vector<f64>,
+	xs: &[Vector<f64>],
+	omega: f64,
+) {
+	let errors = map_starting_points(xs, |x| {
+		let mut x = x.clone();
+
+		iter::once((&x - exact).norm())
+			.chain(iter::from_fn(|| {
+				x = mat.splitting_iteration(&Sor(omega), &x, b);
+				let error = (&x - exact).norm();
+
+				if error < f32::EPSILON.into() {
+					None
+				} else {
+					Some(error)
+				}
+			}))
+			.collect::<Vec<_>>()
+	});
+
+	draw_errors(chart, &errors, &format!("SOR (ω = {omega:.3})"), || {
+		HSLColor(rand::random::<f64>() / 4.0, 0.9, 0.75)
+	})
+	.unwrap();
+}
+
+fn plot_conjugate_gradient(
+	chart: &mut Chart<'_, '_>,
+	mat: &BandMatrix<f64, 2, 2>,
+	b: &Vector<f64>,
+	exact: &Vector<f64>,
+	xs: &[Vector<f64>],
+) {
+	let errors = map_starting_points(xs, |x| {
+		iter::once((x - exact).norm())
+			.chain(
+				mat.conjugate_gradient_iter(x.clone(), b, &Identity)
+					.map(|x| (&x - exact).norm())
+					.take_while(|&error| error >= f32::EPSILON.into()),
+			)
+			.collect::<Vec<_>>()
+	});
+
+	draw_errors(chart, &errors, "Conjugate Gradient", || {
+		HSLColor((rand::random::<f64>() + 2.0) / 4.0, 0.9, 0.75)
+	})
+	.unwrap();
+}
+
+fn plot_chebyshev_jacobi(
+	chart: &mut Chart<'_, '_>,
+	mat: &BandMatrix<f64, 2, 2>,
+	b: &Vector<f64>,
+	exact: &Vector<f64>,
+	xs: &[Vector<f64>],
+	rho: f64,
+) {
+	let errors = map_starting_points(xs, |x| {
+		iter::once((x - exact).norm())
+			.chain(
+				Chebyshev::from_spectral_radius(|x| mat.jacobi_iteration(x, b), x.clone(), rho)
+					.map(|x| (&x - exact).norm())
+					.take_while(|&error| error >= f32::EPSILON.into()),
+			)
+			.collect::<Vec<_>>()
+	});
+
+	draw_errors(chart, &errors, "Chebyshev-Jacobi", || {
+		HSLColor((rand::random::<f64>() + 1.0) / 4.0, 0.9, 0.4)
+	})
+	.unwrap();
+}
+
+fn plot_anderson_gauss_seidel(
+	chart: &mut Chart<'_, '_>,
+	mat: &BandMatrix<f64, 2, 2>,
+	b: &Vector<f64>,
+	exact: &Vector<f64>,
+	xs: &[Vector<f64>],
+) {
+	let errors = map_starting_points(xs, |x| {
+		iter::once((x - exact).norm())
+			.chain(
+				Anderson::new(|x| mat.gauss_seidel_iteration(x, b), x.clone(), 5)
+					.map(|x| (&x - exact).norm())
+					.take_while(|&error| error >= f32::EPSILON.into()),
+			)
+			.collect::<Vec<_>>()
+	});
+
+	draw_errors(chart, &errors, "Anderson(5) Gauss-Seidel", || {
+		HSLColor((rand::random::<f64>() + 3.0) / 4.0, 0.9, 0.4)
+	})
+	.unwrap();
 }
@@ -0,0 +1,311 @@
+use alloc::{collections::VecDeque, vec, vec::Vec};
+
+use num_traits::real::Real;
+
+use crate::vector::Vector;
+
+/// Chebyshev semi-iterative acceleration of a convergent fixed-point map
+/// $f(x) = Gx + c$ (like one Jacobi iteration), where the eigenvalues of $G$
+/// are real and lie in a known interval $[α, β]$ with $β < 1$. Iterating
+/// yields the consecutive approximations of the fixed point.
+pub struct Chebyshev<E, F> {
+	map: F,
+	previous: Option<Vector<E>>,
+	x: Vector<E>,
+	gamma: E,
+	sigma: E,
+	/// $ω_k$ of the last step, or `None` before the second step
+	omega: Option<E>,
+}
+
+/// Anderson acceleration (Anderson mixing) of a fixed-point map, which
+/// extrapolates from the last `m` iterates and their residuals
+/// $g(x) = f(x) - x$. Iterating yields the consecutive approximations of the
+/// fixed point.
+pub struct Anderson<E, F> {
+	map: F,
+	m: usize,
+	x: Vector<E>,
+	g: Option<Vector<E>>,
+	dx: VecDeque<Vector<E>>,
+	dg: VecDeque<Vector<E>>,
+}
+
+impl<E: Real, F: FnMut(&Vector<E>) -> Vector<E>> Chebyshev<E, F> {
+	/// Accelerate `map` starting from `x`, given `bounds` $(α, β)$ of the
+	/// eigenvalues of its iteration matrix
+	pub fn new(map: F, x: Vector<E>, bounds: (E, E)) -> Self {
+		let (alpha, beta) = bounds;
+		assert!(alpha <= beta, "Chebyshev bounds must be ordered");
+		assert!(
+			beta < E::one(),
+			"Chebyshev acceleration requires eigenvalues below 1"
+		);
+
+		let two = E::one() + E::one();
+
+		Self {
+			map,
+			previous: None,
+			x,
+			// The extrapolated map $γf(x) + (1 - γ)x$ has eigenvalues in $[-σ, σ]$
+			gamma: two / (two - beta - alpha),
+			sigma: (beta - alpha) / (two - beta - alpha),
+			omega: None,
+		}
+	}
+
+	/// Accelerate `map` starting from `x`, given the spectral radius `rho` of
+	/// its iteration matrix, assuming its eigenvalues lie in $[-ρ, ρ]$ (which
+	/// is the case for the Jacobi method and symmetric matrices)
+	pub fn from_spectral_radius(map: F, x: Vector<E>, rho: E) -> Self {
+		Self::new(map, x, (-rho, rho))
+	}
+}
+
+impl<E: Real, F: FnMut(&Vector<E>) -> Vector<E>> Iterator for Chebyshev<E, F> {
+	type Item = Vector<E>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let four = E::one() + E::one() + E::one() + E::one();
+		let mapped = (self.map)(&self.x);
+
+		// $T(x_k) = γf(x_k) + (1 - γ)x_k$
+		let extrapolated = Vector::from_iter(
+			mapped
+				.iter()
+				.zip(&self.x)
+				.map(|(&f, &x)| self.gamma * f + (E::one() - self.gamma) * x),
+		);
+
+		// $x_{k+1} = ω_{k+1}(T(x_k) - x_{k-1}) + x_{k-1}$, where
+		// $ω_1 = 1$, $ω_2 = \frac{2}{2 - σ^2}$, $ω_{k+1} = \frac{1}{1 - \frac{σ^2 ω_k}{4}}$
+		let next = match &self.previous {
+			None => extrapolated,
+			Some(previous) => {
+				let sigma2 = self.sigma * self.sigma;
+
+				let omega = match self.omega {
+					None => (E::one() + E::one()) / (E::one() + E::one() - sigma2),
+					Some(omega) => E::one() / (E::one() - sigma2 * omega / four),
+				};
+				self.omega = Some(omega);
+
+				Vector::from_iter(
+					extrapolated
+						.iter()
+						.zip(previous)
+						.map(|(&t, &p)| omega * (t - p) + p),
+				)
+			}
+		};
+
+		self.previous = Some(core::mem::replace(&mut self.x, next));
+		Some(self.x.clone())
+	}
+}
+
+impl<E: Real, F: FnMut(&Vector<E>) -> Vector<E>> Anderson<E, F> {
+	/// Accelerate `map` starting from `x`, using the last `m` iterates
+	pub fn new(map: F, x: Vector<E>, m: usize) -> Self {
+		Self {
+			map,
+			m,
+			x,
+			g: None,
+			dx: VecDeque::with_capacity(m),
+			dg: VecDeque::with_capacity(m),
+		}
+	}
+}
+
+impl<E: Real, F: FnMut(&Vector<E>) -> Vector<E>> Iterator for Anderson<E, F> {
+	type Item = Vector<E>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		// $g_k = f(x_k) - x_k$
+		let mapped = (self.map)(&self.x);
+		let g = &mapped - &self.x;
+
+		if let Some(previous) = self.g.replace(g.clone()) {
+			if self.m > 0 {
+				if self.dg.len() == self.m {
+					self.dx.pop_front();
+					self.dg.pop_front();
+				}
+
+				self.dg.push_back(&g - &previous);
+			}
+		}
+
+		// $γ = \arg\min_γ ||g_k - ΔG γ||$, solved by QR decomposition of $ΔG$
+		let gamma = least_squares(&mut self.dx, &mut self.dg, &g);
+
+		// $x_{k+1} = x_k + g_k - \sum_j γ_j (Δx_j + Δg_j)$
+		let mut next = mapped;
+
+		for ((gamma, dx), dg) in gamma.into_iter().zip(&self.dx).zip(&self.dg) {
+			next = Vector::from_iter(
+				next.iter()
+					.zip(dx)
+					.zip(dg)
+					.map(|((&x, &dx), &dg)| x - gamma * (dx + dg)),
+			);
+		}
+
+		if self.m > 0 {
+			self.dx.push_back(&next - &self.x);
+		}
+
+		self.x = next;
+		Some(self.x.clone())
+	}
+}
+
+/// Solve the least squares problem $\min_γ ||g - ΔG γ||$ using the modified
+/// Gram-Schmidt QR decomposition of $ΔG$, dropping the oldest columns of both
+/// histories if they make $ΔG$ (numerically) rank-deficient
+fn least_squares<E: Real>(
+	dx: &mut VecDeque<Vector<E>>,
+	dg: &mut VecDeque<Vector<E>>,
+	g: &Vector<E>,
+) -> Vec<E> {
+	loop {
+		let k = dg.len();
+		let mut q: Vec<Vector<E>> = Vec::with_capacity(k);
+		let mut r = vec![vec![E::zero(); k]; k];
+		let mut deficient = false;
+
+		for j in 0..k {
+			let mut v = dg[j].clone();
+
+			for (i, qi) in q.iter().enumerate() {
+				r[i][j] = qi.dot(&v);
+				v.axpy(-r[i][j], qi);
+			}
+
+			r[j][j] = v.norm();
+
+			if r[j][j] <= E::epsilon().sqrt() * dg[j].norm() || r[j][j] == E::zero() {
+				deficient = true;
+				break;
+			}
+
+			q.push(v / r[j][j]);
+		}
+
+		if deficient {
+			dx.pop_front();
+			dg.pop_front();
+			continue;
+		}
+
+		// $Rγ = Q^T g$
+		let qg: Vec<E> = q.iter().map(|q| q.dot(g)).collect();
+		let mut gamma = vec![E::zero(); k];
+
+		for i in (0..k).rev() {
+			gamma[i] = (i + 1..k).fold(qg[i], |acc, l| acc - r[i][l] * gamma[l]) / r[i][i];
+		}
+
+		return gamma;
+	}
+}
+
+/// Estimate the spectral radius of the iteration matrix $G$ of an affine
+/// fixed-point map $f(x) = Gx + c$ on vectors of size `n`, using `iters` steps
+/// of the power method on $Gv = f(v) - f(0)$
+pub fn spectral_radius<E: Real>(
+	mut map: impl FnMut(&Vector<E>) -> Vector<E>,
+	n: usize,
+	iters: usize,
+) -> E {
+	let offset = map(&Vector::new(n));
+	let mut v = Vector::from_iter((1..=n).map(|_| E::one()));
+	let norm = v.norm();
+	v /= norm;
+	let mut rho = E::zero();
+
+	for _ in 0..iters {
+		let next = &map(&v) - &offset;
+		rho = next.norm();
+
+		if rho == E::zero() {
+			break;
+		}
+
+		v = next / rho;
+	}
+
+	rho
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_matrices::poisson;
+
+	/// Count the iterations needed to get within `epsilon` of `exact`
+	fn iterations(
+		iter: impl Iterator<Item = Vector<f64>>,
+		exact: &Vector<f64>,
+		epsilon: f64,
+	) -> usize {
+		iter.take(100_000)
+			.position(|x| (&x - exact).norm() < epsilon)
+			.unwrap() + 1
+	}
+
+	#[test]
+	fn chebyshev_jacobi() {
+		let a = poisson(50);
+		let b = Vector::from_iter((1..=50).map(|i| i as f64));
+		let exact = a.clone().lu_decompose().solve(&b);
+		let jacobi = |x: &Vector<f64>| a.jacobi_iteration(x, &b);
+
+		let rho = spectral_radius(jacobi, 50, 2000);
+		assert!((rho - a.jacobi_spectral_radius(2000)).abs() < 1e-9);
+
+		let plain = iterations(
+			core::iter::successors(Some(Vector::new(50)), |x| Some(jacobi(x))).skip(1),
+			&exact,
+			1e-8,
+		);
+		let accelerated = iterations(
+			Chebyshev::from_spectral_radius(jacobi, Vector::new(50), rho),
+			&exact,
+			1e-8,
+		);
+
+		assert!(accelerated * 10 < plain);
+	}
+
+	#[test]
+	fn anderson_gauss_seidel() {
+		let a = crate::test_matrices::num5_matrix(124);
+		let b = crate::test_matrices::num5_b(124);
+		let exact = a.clone().lu_decompose().solve(&b);
+		let gauss_seidel = |x: &Vector<f64>| a.gauss_seidel_iteration(x, &b);
+
+		let plain = iterations(
+			core::iter::successors(Some(Vector::new(124)), |x| Some(gauss_seidel(x))).skip(1),
+			&exact,
+			1e-10,
+		);
+		let accelerated = iterations(
+			Anderson::new(gauss_seidel, Vector::new(124), 5),
+			&exact,
+			1e-10,
+		);
+
+		assert!(accelerated < plain);
+
+		// Anderson(0) is the fixed-point iteration itself
+		let unaccelerated = iterations(
+			Anderson::new(gauss_seidel, Vector::new(124), 0),
+			&exact,
+			1e-10,
+		);
+		assert_eq!(unaccelerated, plain);
+	}
+}
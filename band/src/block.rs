@@ -0,0 +1,537 @@
+use alloc::{boxed::Box, format, vec::Vec};
+#[cfg(feature = "std")]
+use core::fmt::Display;
+use core::{
+	any,
+	fmt::{Debug, Formatter, Result as FmtResult},
+	iter,
+	ops::{Add, Index, IndexMut},
+};
+
+use num_traits::Num;
+
+use crate::vector::Vector;
+
+/// A square block [Band Matrix](https://en.wikipedia.org/wiki/Band_matrix)
+/// with L blocks below/left of the main diagonal and U blocks above/right of
+/// the main diagonal, where every block is a dense square matrix of a size
+/// chosen at runtime. Requires only `O(n)` storage space. Blocks are stored
+/// row-major, in the same order as the elements of a [`BandMatrix`]. Indexing
+/// into the matrix works as usual (using element indices, not block indices),
+/// but with the restriction that all elements outside of the bands are all 0
+/// and read-only (mutable indexing on them will panic).
+///
+/// [`BandMatrix`]: crate::BandMatrix
+#[derive(Clone, PartialEq)]
+pub struct BlockBandMatrix<E, const L: usize, const U: usize> {
+	n: usize,
+	block_size: usize,
+	zero: Box<[E]>,
+	elements: Box<[E]>,
+}
+
+/// The block LU factors of a square block Band Matrix with L blocks
+/// below/left of the main diagonal and U blocks above/right of the main
+/// diagonal. The diagonal blocks of the block U matrix are themselves stored
+/// as their (scalar) LU factors.
+#[derive(Clone, PartialEq)]
+pub struct BlockLuMatrices<E, const L: usize, const U: usize>(BlockBandMatrix<E, L, U>);
+
+impl<E, const L: usize, const U: usize> BlockBandMatrix<E, L, U> {
+	/// Get the number of block rows (and block columns) of this matrix
+	pub fn n(&self) -> usize {
+		self.n
+	}
+
+	/// Get the size of every block
+	pub fn block_size(&self) -> usize {
+		self.block_size
+	}
+
+	/// Get the number of element rows (and element columns) of this matrix
+	pub fn size(&self) -> usize {
+		self.n * self.block_size
+	}
+
+	/// Get the `(i, j)`th block, stored row-major
+	pub fn block(&self, i: usize, j: usize) -> &[E] {
+		if let Some(idx) = self.block_idx((i, j)) {
+			&self.elements[idx..idx + self.block_size * self.block_size]
+		} else {
+			&self.zero
+		}
+	}
+
+	/// Get the `(i, j)`th block mutably, stored row-major
+	pub fn block_mut(&mut self, i: usize, j: usize) -> &mut [E] {
+		if let Some(idx) = self.block_idx((i, j)) {
+			&mut self.elements[idx..idx + self.block_size * self.block_size]
+		} else {
+			panic!("Block {:?} out of bounds for BlockBandMatrix<_, {L}, {U}>({}): mutable indexing can not access zero blocks", (i, j), self.n)
+		}
+	}
+
+	fn block_idx(&self, index: (usize, usize)) -> Option<usize> {
+		assert!(
+			index.0 != 0 && index.1 != 0,
+			"Block {index:?} out of bounds: matrices use 1-based indexing"
+		);
+		assert!(
+			index.0 <= self.n && index.1 <= self.n,
+			"Block {index:?} out of bounds: matrix is {}x{} blocks",
+			self.n,
+			self.n
+		);
+
+		let i = index.0 - 1;
+		let j = index.1 - 1;
+
+		if (i < j && j - i > U) || (i > j && i - j > L) {
+			return None;
+		}
+
+		let block = if i < j {
+			(L + U + 1) * i + L + j.abs_diff(i)
+		} else {
+			(L + U + 1) * i + L - j.abs_diff(i)
+		};
+
+		Some(block * self.block_size * self.block_size)
+	}
+
+	fn idx(&self, index: (usize, usize)) -> Option<usize> {
+		assert!(
+			index.0 != 0 && index.1 != 0,
+			"Index {index:?} out of bounds: matrices use 1-based indexing"
+		);
+		assert!(
+			index.0 <= self.size() && index.1 <= self.size(),
+			"Index {index:?} out of bounds: matrix is {}x{}",
+			self.size(),
+			self.size()
+		);
+
+		let s = self.block_size;
+		let (i, j) = (index.0 - 1, index.1 - 1);
+
+		self.block_idx((i / s + 1, j / s + 1))
+			.map(|idx| idx + (i % s) * s + j % s)
+	}
+}
+
+impl<E: Num, const L: usize, const U: usize> BlockBandMatrix<E, L, U> {
+	/// Create a new zeroed block Band Matrix of n by n blocks, each of size
+	/// `block_size` by `block_size`
+	pub fn new(n: usize, block_size: usize) -> Self {
+		assert_ne!(block_size, 0, "Blocks can't be empty");
+
+		Self {
+			n,
+			block_size,
+			zero: iter::repeat_with(E::zero)
+				.take(block_size * block_size)
+				.collect(),
+			elements: iter::repeat_with(E::zero)
+				.take((U + L + 1) * n * block_size * block_size)
+				.collect(),
+		}
+	}
+}
+
+impl<E: Num + Copy, const L: usize, const U: usize> BlockBandMatrix<E, L, U> {
+	/// Perform in-place block LU decomposition of this matrix (the block
+	/// Thomas algorithm for block tridiagonal matrices). This method assumes
+	/// that the zero element behaves like the number 0, and that the matrix
+	/// and all of the diagonal blocks of its block U matrix admit LU
+	/// decomposition (e.g. that the matrix is block diagonally dominant).
+	/// After this method completes, this matrix will contain the block L and U
+	/// matrices, with the block L matrix's diagonal blocks implied to be
+	/// identity matrices and not stored.
+	pub fn lu_decompose(mut self) -> BlockLuMatrices<E, L, U> {
+		let s = self.block_size;
+
+		for k in 1..=self.n {
+			// $U_{k,m} = A_{k,m} - \sum_{j=\max(1, k - L, m - U)}^{k-1} L_{k,j} U_{j,m}$ for $m = k, k + 1, ..., k + U \le n$
+			for m in k..=(k + U).min(self.n) {
+				let mut block = self.block(k, m).to_vec();
+
+				for j in 1.max(k.saturating_sub(L)).max(m.saturating_sub(U))..=k - 1 {
+					mul_sub(&mut block, self.block(k, j), self.block(j, m), s);
+				}
+
+				self.block_mut(k, m).copy_from_slice(&block);
+			}
+
+			// $U_{k,k} = P_k Q_k$
+			factorize(self.block_mut(k, k), s);
+
+			// $L_{i,k} = (A_{i,k} - \sum_{j=\max(1, i - L, k - U)}^{k-1} L_{i,j} U_{j,k}) U_{k,k}^{-1}$ for $i = k + 1, k + 2, ..., k + L \le n$
+			for i in k + 1..=(k + L).min(self.n) {
+				let mut block = self.block(i, k).to_vec();
+
+				for j in 1.max(i.saturating_sub(L)).max(k.saturating_sub(U))..=k - 1 {
+					mul_sub(&mut block, self.block(i, j), self.block(j, k), s);
+				}
+
+				solve_right(&mut block, self.block(k, k), s);
+				self.block_mut(i, k).copy_from_slice(&block);
+			}
+		}
+
+		BlockLuMatrices(self)
+	}
+}
+
+impl<E, const L: usize, const U: usize> BlockLuMatrices<E, L, U> {
+	/// Get the factors of this matrix, with the block L matrix's diagonal
+	/// blocks implied to be identity matrices and the block U matrix's
+	/// diagonal blocks stored as their LU factors
+	pub fn factors(&self) -> &BlockBandMatrix<E, L, U> {
+		&self.0
+	}
+}
+
+impl<E: Num + Copy, const L: usize, const U: usize> BlockLuMatrices<E, L, U> {
+	pub fn det(&self) -> E {
+		let s = self.0.block_size;
+		let mut res = E::one();
+
+		// $\det A = \prod_{k=1}^n \det U_{k,k} = \prod_{k=1}^n \det Q_k$
+		for k in 1..=self.0.n {
+			let block = self.0.block(k, k);
+
+			for r in 0..s {
+				res = res * block[r * s + r];
+			}
+		}
+
+		res
+	}
+
+	pub fn solve(&self, b: &Vector<E>) -> Vector<E> {
+		assert_eq!(
+			self.0.size(),
+			b.n(),
+			"Can't solve system of equations for a matrix and vector with different heights"
+		);
+
+		let s = self.0.block_size;
+		let mut x: Vec<E> = (1..=b.n()).map(|i| b[i]).collect();
+
+		// $Ly = b$
+		// $y_m = b_m - \sum_{i=\max(1, m - L)}^{m-1} L_{m,i} y_i$ for $m = 1, ..., n$
+		for m in 1..=self.0.n {
+			let (solved, rest) = x.split_at_mut((m - 1) * s);
+
+			for i in 1.max(m.saturating_sub(L))..=m - 1 {
+				mul_sub(
+					&mut rest[..s],
+					self.0.block(m, i),
+					&solved[(i - 1) * s..i * s],
+					s,
+				);
+			}
+		}
+
+		// $Ux = y$
+		// $x_m = U_{m,m}^{-1} (y_m - \sum_{i=m+1}^{\min(n, m + U)} U_{m,i} x_i)$ for $m = n, ..., 1$
+		for m in (1..=self.0.n).rev() {
+			let (rest, solved) = x.split_at_mut(m * s);
+			let y = &mut rest[(m - 1) * s..];
+
+			for i in m + 1..=self.0.n.min(m + U) {
+				mul_sub(
+					y,
+					self.0.block(m, i),
+					&solved[(i - m - 1) * s..(i - m) * s],
+					s,
+				);
+			}
+
+			solve_left(y, self.0.block(m, m), s);
+		}
+
+		Vector::from_iter(x)
+	}
+}
+
+/// Compute $C = C - AB$, where $A$ is a row-major `s` by `s` block and $B$
+/// and $C$ are row-major blocks with `s` rows (and any number of columns)
+fn mul_sub<E: Num + Copy>(c: &mut [E], a: &[E], b: &[E], s: usize) {
+	let cols = c.len() / s;
+
+	for r in 0..s {
+		for col in 0..cols {
+			c[r * cols + col] = c[r * cols + col]
+				- (0..s)
+					.map(|t| a[r * s + t] * b[t * cols + col])
+					.reduce(Add::add)
+					.unwrap_or_else(E::zero);
+		}
+	}
+}
+
+/// Perform in-place LU decomposition of a dense row-major `s` by `s` block
+/// $B = PQ$, with $P$'s diagonal elements implied to be 1 and not stored
+fn factorize<E: Num + Copy>(block: &mut [E], s: usize) {
+	for k in 0..s {
+		for i in k + 1..s {
+			block[i * s + k] = block[i * s + k] / block[k * s + k];
+
+			for j in k + 1..s {
+				block[i * s + j] = block[i * s + j] - block[i * s + k] * block[k * s + j];
+			}
+		}
+	}
+}
+
+/// Compute $x = (PQ)^{-1} x$ in place, given the LU factors of a block
+fn solve_left<E: Num + Copy>(x: &mut [E], lu: &[E], s: usize) {
+	// $Pz = x$
+	for m in 0..s {
+		for i in 0..m {
+			x[m] = x[m] - lu[m * s + i] * x[i];
+		}
+	}
+
+	// $Qx = z$
+	for m in (0..s).rev() {
+		for i in m + 1..s {
+			x[m] = x[m] - lu[m * s + i] * x[i];
+		}
+
+		x[m] = x[m] / lu[m * s + m];
+	}
+}
+
+/// Compute $X = X (PQ)^{-1}$ in place, given the LU factors of a block and a
+/// row-major `s` by `s` block $X$
+fn solve_right<E: Num + Copy>(x: &mut [E], lu: &[E], s: usize) {
+	for row in x.chunks_exact_mut(s) {
+		// $zQ = x$
+		for c in 0..s {
+			for t in 0..c {
+				row[c] = row[c] - row[t] * lu[t * s + c];
+			}
+
+			row[c] = row[c] / lu[c * s + c];
+		}
+
+		// $xP = z$
+		for c in (0..s).rev() {
+			for t in c + 1..s {
+				row[c] = row[c] - row[t] * lu[t * s + c];
+			}
+		}
+	}
+}
+
+impl<E: Debug, const L: usize, const U: usize> Debug for BlockBandMatrix<E, L, U> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.debug_struct(&format!(
+			"BlockBandMatrix<{}, L = {L}, U = {U}>",
+			any::type_name::<E>()
+		))
+		.field("n", &self.n)
+		.field("block_size", &self.block_size)
+		.field("elements", &self.elements)
+		.finish()
+	}
+}
+
+impl<E: Debug, const L: usize, const U: usize> Debug for BlockLuMatrices<E, L, U> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		self.0.fmt(f)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<E: Display, const L: usize, const U: usize> Display for BlockBandMatrix<E, L, U> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		let n = self.size();
+		let width = f.width().unwrap_or(1);
+		let precision = f.precision().unwrap_or(6);
+		let full_width = (width * n + n - 1).max((2 + precision) * n + n - 1);
+
+		write!(f, "┌ ")?;
+		for _ in 0..full_width {
+			write!(f, " ")?;
+		}
+		writeln!(f, " ┐")?;
+
+		for i in 1..=n {
+			write!(f, "│ ")?;
+
+			for j in 1..=n {
+				write!(
+					f,
+					"{:width$.precision$} ",
+					self[(i, j)],
+					width = width,
+					precision = precision
+				)?;
+			}
+
+			writeln!(f, "│")?;
+		}
+
+		write!(f, "└ ")?;
+		for _ in 0..full_width {
+			write!(f, " ")?;
+		}
+		writeln!(f, " ┘{n}×{n} ({}×{} blocks)", self.n, self.n)?;
+
+		Ok(())
+	}
+}
+
+#[cfg(feature = "std")]
+impl<E: Display, const L: usize, const U: usize> Display for BlockLuMatrices<E, L, U> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		self.0.fmt(f)
+	}
+}
+
+impl<E, const L: usize, const U: usize> Index<(usize, usize)> for BlockBandMatrix<E, L, U> {
+	type Output = E;
+
+	fn index(&self, index: (usize, usize)) -> &Self::Output {
+		if let Some(idx) = self.idx(index) {
+			&self.elements[idx]
+		} else {
+			&self.zero[0]
+		}
+	}
+}
+
+impl<E, const L: usize, const U: usize> IndexMut<(usize, usize)> for BlockBandMatrix<E, L, U> {
+	fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+		if let Some(idx) = self.idx(index) {
+			&mut self.elements[idx]
+		} else {
+			panic!("Index {index:?} out of bounds for BlockBandMatrix<_, {L}, {U}>({}): mutable indexing can not access zero elements", self.n)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use nalgebra::{DMatrix, DVector};
+
+	use super::*;
+
+	/// A block tridiagonal matrix like the ones from two coupled 1D diffusion
+	/// equations, with 2 by 2 blocks
+	fn coupled(n: usize) -> BlockBandMatrix<f64, 1, 1> {
+		let mut a = BlockBandMatrix::new(n, 2);
+
+		for k in 1..=n {
+			a.block_mut(k, k).copy_from_slice(&[4.0, 0.5, -0.3, 5.0]);
+		}
+
+		for k in 1..n {
+			a.block_mut(k, k + 1)
+				.copy_from_slice(&[-1.0, 0.1, 0.0, -1.0]);
+			a.block_mut(k + 1, k)
+				.copy_from_slice(&[-1.0, 0.0, 0.2, -1.5 / k as f64]);
+		}
+
+		a
+	}
+
+	fn to_nalgebra<const L: usize, const U: usize>(a: &BlockBandMatrix<f64, L, U>) -> DMatrix<f64> {
+		DMatrix::from_fn(a.size(), a.size(), |i, j| a[(i + 1, j + 1)])
+	}
+
+	#[test]
+	fn indexing() {
+		let a = coupled(3);
+
+		assert_eq!(a[(3, 3)], 4.0);
+		assert_eq!(a[(3, 4)], 0.5);
+		assert_eq!(a[(4, 3)], -0.3);
+		assert_eq!(a[(1, 4)], 0.1);
+		assert_eq!(a[(2, 3)], 0.0);
+		assert_eq!(a[(6, 4)], -0.75);
+		assert_eq!(a[(1, 5)], 0.0);
+		assert_eq!(a.block(1, 3), &[0.0; 4]);
+	}
+
+	#[test]
+	fn solve_matches_nalgebra() {
+		let a = coupled(40);
+		let nal = to_nalgebra(&a);
+		let lu = a.lu_decompose();
+
+		let b = Vector::from_iter((1..=80).map(|i| (i % 7) as f64));
+		let bnal = DVector::from_fn(80, |i, _| ((i + 1) % 7) as f64);
+
+		let x = lu.solve(&b);
+		let xnal = nal.clone().lu().solve(&bnal).unwrap();
+
+		for i in 1..=80 {
+			assert!((x[i] - xnal[i - 1]).abs() < 1e-12);
+		}
+
+		assert!((lu.det() - nal.determinant()).abs() < 1e-9 * lu.det().abs());
+	}
+
+	#[test]
+	fn wider_bands() {
+		let n = 12;
+		let s = 3;
+		let mut a = BlockBandMatrix::<f64, 2, 1>::new(n, s);
+
+		for k in 1..=n {
+			for (l, m) in [(k, k), (k + 1, k), (k + 2, k), (k, k + 1)] {
+				if l > n || m > n {
+					continue;
+				}
+
+				for (idx, e) in a.block_mut(l, m).iter_mut().enumerate() {
+					*e = if l == m && idx % (s + 1) == 0 {
+						10.0
+					} else {
+						((idx + l * m) % 5) as f64 / 5.0 - 0.4
+					};
+				}
+			}
+		}
+
+		let nal = to_nalgebra(&a);
+		let lu = a.lu_decompose();
+
+		let b = Vector::from_iter((1..=n * s).map(|i| i as f64));
+		let bnal = DVector::from_fn(n * s, |i, _| (i + 1) as f64);
+
+		let x = lu.solve(&b);
+		let xnal = nal.lu().solve(&bnal).unwrap();
+
+		for i in 1..=n * s {
+			assert!((x[i] - xnal[i - 1]).abs() < 1e-12);
+		}
+	}
+
+	#[test]
+	fn unit_blocks_equal_band_lu() {
+		let mut a = BlockBandMatrix::<f64, 1, 2>::new(30, 1);
+		let band = crate::test_matrices::num3_matrix(30);
+
+		for i in 1..=30usize {
+			for j in 1.max(i.saturating_sub(1))..=(i + 2).min(30) {
+				a[(i, j)] = band[(i, j)];
+			}
+		}
+
+		let lu = a.lu_decompose();
+		let band = band.lu_decompose();
+
+		for i in 1..=30 {
+			for j in 1..=30 {
+				assert_eq!(lu.factors()[(i, j)], band[(i, j)]);
+			}
+		}
+
+		assert_eq!(lu.det(), band.det());
+	}
+}
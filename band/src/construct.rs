@@ -0,0 +1,257 @@
+use core::{
+	error::Error,
+	fmt::{Display, Formatter, Result as FmtResult},
+};
+
+use num_traits::Num;
+
+use crate::matrix::BandMatrix;
+
+/// The reason why a [`BandMatrix`] can't be built from the given elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BandMatrixError {
+	/// The number of diagonals isn't `L + U + 1`
+	DiagonalCount { expected: usize, found: usize },
+	/// A diagonal has the wrong number of elements. `offset` is $j - i$ for
+	/// the elements $a_{i,j}$ on the diagonal.
+	DiagonalLength {
+		offset: isize,
+		expected: usize,
+		found: usize,
+	},
+	/// A row of a dense matrix doesn't have as many elements as there are rows
+	RowLength {
+		row: usize,
+		expected: usize,
+		found: usize,
+	},
+	/// A dense matrix has a non-zero element $a_{i,j}$ outside of the bands
+	OutsideBand { row: usize, column: usize },
+}
+
+impl Display for BandMatrixError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::DiagonalCount { expected, found } => {
+				write!(f, "expected {expected} diagonals, found {found}")
+			}
+			Self::DiagonalLength {
+				offset,
+				expected,
+				found,
+			} => write!(
+				f,
+				"expected {expected} elements on the diagonal with offset {offset}, found {found}"
+			),
+			Self::RowLength {
+				row,
+				expected,
+				found,
+			} => write!(
+				f,
+				"expected {expected} elements in row {row}, found {found}"
+			),
+			Self::OutsideBand { row, column } => {
+				write!(
+					f,
+					"non-zero element at ({row}, {column}) outside of the bands"
+				)
+			}
+		}
+	}
+}
+
+impl Error for BandMatrixError {}
+
+impl<E: Num + Copy, const L: usize, const U: usize> BandMatrix<E, L, U> {
+	/// Create an n by n Band Matrix with $a_{i,j} = f(i, j)$ inside of the
+	/// bands. `f` is called only inside of the bands, with 1-based indices.
+	pub fn from_fn(n: usize, mut f: impl FnMut(usize, usize) -> E) -> Self {
+		let mut res = Self::new(n);
+
+		for i in 1..=n {
+			for j in 1.max(i.saturating_sub(L))..=(i + U).min(n) {
+				res[(i, j)] = f(i, j);
+			}
+		}
+
+		res
+	}
+
+	/// Create a Band Matrix from its `L + U + 1` diagonals, from the lowest to
+	/// the highest. The size of the matrix is the length of the main diagonal,
+	/// and the diagonal $d$ places away from it must be $d$ elements shorter.
+	pub fn from_diagonals(diagonals: &[&[E]]) -> Result<Self, BandMatrixError> {
+		if diagonals.len() != L + U + 1 {
+			return Err(BandMatrixError::DiagonalCount {
+				expected: L + U + 1,
+				found: diagonals.len(),
+			});
+		}
+
+		let n = diagonals[L].len();
+
+		for (k, diagonal) in diagonals.iter().enumerate() {
+			let expected = n.saturating_sub(k.abs_diff(L));
+
+			if diagonal.len() != expected {
+				return Err(BandMatrixError::DiagonalLength {
+					offset: k as isize - L as isize,
+					expected,
+					found: diagonal.len(),
+				});
+			}
+		}
+
+		// $a_{i,j}$ is the element `min(i, j) - 1` of the diagonal `L + j - i`
+		Ok(Self::from_fn(n, |i, j| diagonals[L + j - i][i.min(j) - 1]))
+	}
+
+	/// Create an n by n Band Matrix with every diagonal constant, given the
+	/// values of its `L + U + 1` diagonals, from the lowest to the highest (see
+	/// [`ToeplitzBand`](crate::ToeplitzBand) for the `O(1)` storage version)
+	pub fn from_constant_diagonals(n: usize, diagonals: &[E]) -> Result<Self, BandMatrixError> {
+		if diagonals.len() != L + U + 1 {
+			return Err(BandMatrixError::DiagonalCount {
+				expected: L + U + 1,
+				found: diagonals.len(),
+			});
+		}
+
+		Ok(Self::from_fn(n, |i, j| diagonals[L + j - i]))
+	}
+
+	/// Create a Band Matrix from a dense square matrix given as a list of
+	/// rows, failing if any element outside of the bands isn't 0
+	pub fn try_from_dense<R: AsRef<[E]>>(rows: &[R]) -> Result<Self, BandMatrixError> {
+		let n = rows.len();
+
+		for (i, row) in rows.iter().enumerate() {
+			if row.as_ref().len() != n {
+				return Err(BandMatrixError::RowLength {
+					row: i + 1,
+					expected: n,
+					found: row.as_ref().len(),
+				});
+			}
+		}
+
+		Self::try_from_dense_fn(n, |i, j| rows[i - 1].as_ref()[j - 1])
+	}
+
+	/// Create an n by n Band Matrix with $a_{i,j} = f(i, j)$ (with 1-based
+	/// indices), failing if $f$ isn't 0 outside of the bands
+	pub(crate) fn try_from_dense_fn(
+		n: usize,
+		mut f: impl FnMut(usize, usize) -> E,
+	) -> Result<Self, BandMatrixError> {
+		for i in 1..=n {
+			let band = i.saturating_sub(L).max(1)..=(i + U).min(n);
+
+			for j in (1..=n).filter(|j| !band.contains(j)) {
+				if !f(i, j).is_zero() {
+					return Err(BandMatrixError::OutsideBand { row: i, column: j });
+				}
+			}
+		}
+
+		Ok(Self::from_fn(n, f))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::{vec, vec::Vec};
+
+	use super::*;
+	use crate::test_matrices::{num3_matrix, num5_matrix, num5_toeplitz};
+
+	#[test]
+	fn from_diagonals() {
+		let n = 30;
+		let a = num3_matrix(n);
+		let lower: Vec<f64> = (1..n).map(|_| 0.2).collect();
+		let diagonal: Vec<f64> = (1..=n).map(|_| 1.2).collect();
+		let upper1: Vec<f64> = (1..n).map(|i| 0.1 / i as f64).collect();
+		let upper2: Vec<f64> = (1..n - 1).map(|i| 0.15 / (i as f64).powi(2)).collect();
+
+		let b = BandMatrix::<f64, 1, 2>::from_diagonals(&[&lower, &diagonal, &upper1, &upper2]);
+		assert!(b.unwrap() == a);
+
+		assert_eq!(
+			BandMatrix::<f64, 1, 2>::from_diagonals(&[&lower, &diagonal, &upper1]).err(),
+			Some(BandMatrixError::DiagonalCount {
+				expected: 4,
+				found: 3
+			})
+		);
+		assert_eq!(
+			BandMatrix::<f64, 1, 2>::from_diagonals(&[&lower, &diagonal, &upper1, &upper1]).err(),
+			Some(BandMatrixError::DiagonalLength {
+				offset: 2,
+				expected: n - 2,
+				found: n - 1
+			})
+		);
+	}
+
+	#[test]
+	fn from_constant_diagonals() {
+		let diagonals = [0.15, 1.0, 3.0, 1.0, 0.15];
+		let a = BandMatrix::<f64, 2, 2>::from_constant_diagonals(40, &diagonals).unwrap();
+
+		assert!(a == num5_matrix(40));
+		assert!(a == num5_toeplitz(40).to_band());
+		assert!(BandMatrix::<f64, 2, 2>::from_constant_diagonals(40, &diagonals[1..]).is_err());
+
+		// Diagonals that don't fit in tiny matrices are dropped
+		for n in 0..=3 {
+			let a = BandMatrix::<f64, 2, 2>::from_constant_diagonals(n, &diagonals).unwrap();
+			assert_eq!(a.n(), n);
+			assert!(a == BandMatrix::from_fn(n, |i, j| diagonals[2 + j - i]));
+		}
+	}
+
+	#[test]
+	fn from_fn() {
+		let mut calls = 0;
+		let a = BandMatrix::<f64, 1, 2>::from_fn(10, |i, j| {
+			calls += 1;
+			assert!(i <= j + 1 && j <= i + 2);
+			(i * 10 + j) as f64
+		});
+
+		assert_eq!(calls, 10 + 9 + 9 + 8);
+		assert_eq!(a[(3, 5)], 35.0);
+		assert_eq!(a[(5, 3)], 0.0);
+	}
+
+	#[test]
+	fn try_from_dense() {
+		let rows = vec![
+			vec![4.0, 1.0, 0.0],
+			vec![2.0, 4.0, 1.0],
+			vec![0.0, 2.0, 4.0],
+		];
+		let a = BandMatrix::<f64, 1, 1>::try_from_dense(&rows).unwrap();
+
+		for i in 1..=3 {
+			for j in 1..=3 {
+				assert_eq!(a[(i, j)], rows[i - 1][j - 1]);
+			}
+		}
+
+		assert_eq!(
+			BandMatrix::<f64, 0, 1>::try_from_dense(&rows).err(),
+			Some(BandMatrixError::OutsideBand { row: 2, column: 1 })
+		);
+		assert_eq!(
+			BandMatrix::<f64, 1, 1>::try_from_dense(&[[1.0, 0.0], [0.0, 1.0], [0.0, 0.0]]).err(),
+			Some(BandMatrixError::RowLength {
+				row: 1,
+				expected: 3,
+				found: 2
+			})
+		);
+	}
+}
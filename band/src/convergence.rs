@@ -0,0 +1,37 @@
+use alloc::vec::Vec;
+
+use crate::vector::Vector;
+
+/// The stopping criteria of an iterative method
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Criteria<E> {
+	/// The method stops once the relative residual $\frac{||b - Ax^{(k)}||}{||b||}$
+	/// falls below this value
+	pub epsilon: E,
+	/// The maximum number of iterations to perform
+	pub max_iters: usize,
+}
+
+/// The result and convergence history of an iterative method
+#[derive(Debug, Clone, PartialEq)]
+pub struct Convergence<E> {
+	/// The last computed approximation of the solution
+	pub x: Vector<E>,
+	/// The residual norms $||b - Ax^{(k)}||$ for $k = 0, 1, ...$, starting with
+	/// the initial guess
+	pub residuals: Vec<E>,
+	/// Whether the stopping criteria were met within the iteration limit
+	pub converged: bool,
+}
+
+impl<E> Convergence<E> {
+	/// Get the number of performed iterations
+	pub fn iterations(&self) -> usize {
+		self.residuals.len().saturating_sub(1)
+	}
+
+	/// Get the solution if the method converged
+	pub fn solution(self) -> Option<Vector<E>> {
+		self.converged.then_some(self.x)
+	}
+}
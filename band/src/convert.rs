@@ -0,0 +1,81 @@
+use core::ops::Deref;
+
+use nalgebra::{DMatrix, DVector, Scalar};
+use num_traits::Num;
+
+use crate::{construct::BandMatrixError, matrix::BandMatrix, vector::Vector};
+
+impl<E: Scalar, const L: usize, const U: usize, S: Deref<Target = [E]>>
+	From<&BandMatrix<E, L, U, S>> for DMatrix<E>
+{
+	/// Copy the band matrix into a dense nalgebra matrix
+	fn from(matrix: &BandMatrix<E, L, U, S>) -> Self {
+		DMatrix::from_fn(matrix.n(), matrix.n(), |i, j| {
+			matrix[(i + 1, j + 1)].clone()
+		})
+	}
+}
+
+impl<E: Scalar + Num + Copy, const L: usize, const U: usize> TryFrom<&DMatrix<E>>
+	for BandMatrix<E, L, U>
+{
+	type Error = BandMatrixError;
+
+	/// Copy a square dense nalgebra matrix into a band matrix, failing if any
+	/// element outside of the bands isn't 0
+	fn try_from(matrix: &DMatrix<E>) -> Result<Self, Self::Error> {
+		if !matrix.is_square() {
+			return Err(BandMatrixError::RowLength {
+				row: 1,
+				expected: matrix.nrows(),
+				found: matrix.ncols(),
+			});
+		}
+
+		Self::try_from_dense_fn(matrix.nrows(), |i, j| matrix[(i - 1, j - 1)])
+	}
+}
+
+impl<E: Scalar, S: Deref<Target = [E]>> From<&Vector<E, S>> for DVector<E> {
+	fn from(vector: &Vector<E, S>) -> Self {
+		DVector::from_iterator(vector.n(), vector.iter().cloned())
+	}
+}
+
+impl<E: Scalar> From<DVector<E>> for Vector<E> {
+	fn from(vector: DVector<E>) -> Self {
+		Vector::from_iter(vector.iter().cloned())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use nalgebra::{DMatrix, DVector};
+
+	use crate::{
+		construct::BandMatrixError, matrix::BandMatrix, test_matrices::num3_matrix, vector::Vector,
+	};
+
+	#[test]
+	fn round_trip() {
+		let a = num3_matrix(20);
+		let dense = DMatrix::from(&a);
+
+		for i in 1..=20 {
+			for j in 1..=20 {
+				assert_eq!(dense[(i - 1, j - 1)], a[(i, j)]);
+			}
+		}
+
+		let x = Vector::from_iter((1..=20).map(|i| i as f64 / 7.0));
+		let ax = &a * &x;
+		let ax_dense = &dense * DVector::from(&x);
+
+		assert_eq!(Vector::from(ax_dense), ax);
+		assert!(BandMatrix::<f64, 1, 2>::try_from(&dense).unwrap() == a);
+		assert_eq!(
+			BandMatrix::<f64, 1, 1>::try_from(&dense).err(),
+			Some(BandMatrixError::OutsideBand { row: 1, column: 3 })
+		);
+	}
+}
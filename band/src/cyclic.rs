@@ -0,0 +1,194 @@
+use core::ops::Mul;
+
+use num_traits::Num;
+
+use crate::{
+	matrix::BandMatrix, rank_one::BandPlusRankOne, sherman_morrison, simd::MaybeSimd,
+	vector::Vector,
+};
+
+/// A square cyclic (periodic) tridiagonal matrix, i.e. a tridiagonal matrix
+/// with the additional corner elements $a_{1,n}$ and $a_{n,1}$, as produced by
+/// periodic boundary conditions. Requires only `O(n)` storage space.
+#[derive(Clone, PartialEq)]
+pub struct CyclicTridiagonal<E> {
+	band: BandMatrix<E, 1, 1>,
+	top_right: E,
+	bottom_left: E,
+}
+
+impl<E> CyclicTridiagonal<E> {
+	/// Create the matrix with the tridiagonal part `band` and the corner
+	/// elements $a_{1,n}$ = `top_right` and $a_{n,1}$ = `bottom_left`
+	pub fn new(band: BandMatrix<E, 1, 1>, top_right: E, bottom_left: E) -> Self {
+		assert!(
+			band.n() >= 3,
+			"Cyclic tridiagonal matrices must be at least 3x3"
+		);
+
+		Self {
+			band,
+			top_right,
+			bottom_left,
+		}
+	}
+
+	pub fn n(&self) -> usize {
+		self.band.n()
+	}
+
+	/// Get the tridiagonal part of this matrix
+	pub fn band(&self) -> &BandMatrix<E, 1, 1> {
+		&self.band
+	}
+}
+
+impl<E: Num + Copy> CyclicTridiagonal<E> {
+	/// Get the `(i, j)`th element of this matrix
+	pub fn get(&self, i: usize, j: usize) -> E {
+		match (i, j) {
+			(1, j) if j == self.n() => self.top_right,
+			(i, 1) if i == self.n() => self.bottom_left,
+			_ => self.band[(i, j)],
+		}
+	}
+}
+
+impl<E: Num + Copy + MaybeSimd> CyclicTridiagonal<E> {
+	/// Split this matrix into $A' + uv^T$, where $A'$ is tridiagonal,
+	/// $u = [ γ 0 ... 0 a_{n,1} ]^T$ and $v = [ 1 0 ... 0 \frac{a_{1,n}}{γ} ]^T$,
+	/// with $γ = -a_{1,1}$ (or $-1$ if $a_{1,1} = 0$) to avoid cancellation in
+	/// $a'_{1,1} = a_{1,1} - γ$
+	pub fn to_rank_one(&self) -> BandPlusRankOne<E, 1, 1> {
+		let n = self.n();
+		let gamma = self.gamma();
+		let mut a_prime = self.band.clone();
+		let mut u = Vector::new(n);
+		let mut v = Vector::new(n);
+
+		a_prime[(1, 1)] = a_prime[(1, 1)] - gamma;
+		a_prime[(n, n)] = a_prime[(n, n)] - self.bottom_left * self.top_right / gamma;
+
+		u[1] = gamma;
+		u[n] = self.bottom_left;
+		v[1] = E::one();
+		v[n] = self.top_right / gamma;
+
+		BandPlusRankOne::new(a_prime, u, v)
+	}
+
+	/// Get the determinant of this matrix. This method assumes that the
+	/// tridiagonal part of [`Self::to_rank_one`] admits LU decomposition.
+	pub fn det(&self) -> E {
+		self.to_rank_one().det()
+	}
+
+	/// Solve $Ax = b$ for $x$ using the Sherman-Morrison formula on
+	/// [`Self::to_rank_one`], solving both tridiagonal systems with the Thomas
+	/// algorithm
+	pub fn solve(&self, b: &Vector<E>) -> Vector<E> {
+		assert_eq!(self.n(), b.n(), "A's height must equal b's size");
+
+		let a = self.to_rank_one();
+
+		// $A'y = b$, $A'z = u$
+		let mut x = a.band().thomas_solve(b);
+		let z = a.band().thomas_solve(a.u());
+
+		// $x = y - \frac{zv^Ty}{1 + v^Tz}$
+		let denominator = E::one() + a.v().dot(&z);
+		sherman_morrison::update(&mut x, &z, a.v(), denominator);
+		x
+	}
+
+	fn gamma(&self) -> E {
+		let diagonal = self.band[(1, 1)];
+
+		if diagonal == E::zero() {
+			E::zero() - E::one()
+		} else {
+			E::zero() - diagonal
+		}
+	}
+}
+
+impl<E: Num + Copy> Mul<&Vector<E>> for &CyclicTridiagonal<E> {
+	type Output = Vector<E>;
+
+	fn mul(self, rhs: &Vector<E>) -> Self::Output {
+		let n = self.n();
+		let mut res = &self.band * rhs;
+
+		res[1] = res[1] + self.top_right * rhs[n];
+		res[n] = res[n] + self.bottom_left * rhs[1];
+
+		res
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use nalgebra::{DMatrix, DVector};
+
+	use super::*;
+	use crate::{convergence::Criteria, operator::LinearOperator};
+
+	#[test]
+	fn cyclic_tridiagonal() {
+		let n = 30;
+		let mut band = BandMatrix::<f64, 1, 1>::new(n);
+		let mut nal = DMatrix::zeros(n, n);
+
+		for i in 1..=n {
+			band[(i, i)] = 3.0;
+			nal[(i - 1, i - 1)] = 3.0;
+		}
+
+		for i in 1..n {
+			band[(i, i + 1)] = 1.0;
+			band[(i + 1, i)] = -0.5;
+			nal[(i - 1, i)] = 1.0;
+			nal[(i, i - 1)] = -0.5;
+		}
+
+		nal[(0, n - 1)] = -0.5;
+		nal[(n - 1, 0)] = 1.0;
+		let a = CyclicTridiagonal::new(band, -0.5, 1.0);
+
+		for i in 1..=n {
+			for j in 1..=n {
+				assert_eq!(a.get(i, j), nal[(i - 1, j - 1)]);
+				assert!((a.to_rank_one().get(i, j) - nal[(i - 1, j - 1)]).abs() < 1e-15);
+			}
+		}
+
+		let b = Vector::from_iter((1..=n).map(|i| (i % 4) as f64));
+		let x = a.solve(&b);
+		let xnal = nal
+			.clone()
+			.lu()
+			.solve(&DVector::from_iterator(n, b.clone()))
+			.unwrap();
+
+		for i in 1..=n {
+			assert!((x[i] - xnal[i - 1]).abs() < 1e-13);
+		}
+
+		let bicgstab = a.bicgstab(
+			Vector::new(n),
+			&b,
+			Criteria {
+				epsilon: 1e-13,
+				max_iters: 100,
+			},
+		);
+		assert!(bicgstab.converged);
+		assert!((&bicgstab.x - &x).norm() < 1e-11);
+
+		let residual = &a * &x - b;
+		assert!(residual.iter().all(|r| r.abs() < 1e-13));
+
+		let det = a.det();
+		assert!((det - nal.determinant()).abs() <= 1e-12 * det.abs());
+	}
+}
@@ -0,0 +1,74 @@
+use alloc::{vec, vec::Vec};
+use core::{cmp::Ordering, ops::Neg};
+
+use num_traits::Num;
+
+/// Solve the dense system $Ax = b$ (with `a` given as a list of rows) using
+/// Gaussian elimination with partial pivoting. Fails if $A$ is singular or a
+/// pivot candidate is NaN.
+pub fn solve<E: Num + Neg<Output = E> + PartialOrd + Copy>(
+	mut a: Vec<Vec<E>>,
+	mut b: Vec<E>,
+) -> Option<Vec<E>> {
+	let k = b.len();
+	assert_eq!(a.len(), k, "A's height must equal b's size");
+
+	let abs = |e: E| if e < E::zero() { -e } else { e };
+
+	for col in 0..k {
+		let pivot = (col + 1..k).try_fold(col, |pivot, i| {
+			match abs(a[i][col]).partial_cmp(&abs(a[pivot][col]))? {
+				Ordering::Greater => Some(i),
+				_ => Some(pivot),
+			}
+		})?;
+
+		// Written to also catch a NaN pivot
+		if abs(a[pivot][col]).partial_cmp(&E::zero()) != Some(Ordering::Greater) {
+			return None;
+		}
+
+		a.swap(col, pivot);
+		b.swap(col, pivot);
+
+		let (top, bottom) = a.split_at_mut(col + 1);
+		let pivot_row = &top[col];
+
+		for (row, current) in (col + 1..k).zip(bottom) {
+			let factor = current[col] / pivot_row[col];
+
+			for (e, &p) in current[col..].iter_mut().zip(&pivot_row[col..]) {
+				*e = *e - factor * p;
+			}
+
+			b[row] = b[row] - factor * b[col];
+		}
+	}
+
+	let mut x = vec![E::zero(); k];
+
+	for row in (0..k).rev() {
+		x[row] = (row + 1..k).fold(b[row], |acc, i| acc - a[row][i] * x[i]) / a[row][row];
+	}
+
+	Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn solve() {
+		// The first pivot is in the second row
+		let a = vec![vec![1.0, 2.0], vec![4.0, 1.0f64]];
+		let x = super::solve(a, vec![5.0, 6.0]).unwrap();
+		assert!((x[0] - 1.0).abs() < 1e-15 && (x[1] - 2.0).abs() < 1e-15);
+
+		let singular = vec![vec![1.0, 2.0], vec![2.0, 4.0]];
+		assert_eq!(super::solve(singular, vec![1.0, 2.0]), None);
+
+		let nan = vec![vec![1.0, 2.0], vec![f64::NAN, 1.0]];
+		assert_eq!(super::solve(nan, vec![1.0, 2.0]), None);
+	}
+}
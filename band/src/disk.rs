@@ -0,0 +1,576 @@
+use std::{
+	cmp::Ordering,
+	fs::File,
+	io::{self, Error, ErrorKind, Read, Seek, SeekFrom, Write},
+	marker::PhantomData,
+	ops::Add,
+	path::Path,
+};
+
+use num_traits::{FromBytes, Num, ToBytes};
+
+use crate::vector::Vector;
+
+/// The number of rows read into memory at once
+const CHUNK_ROWS: usize = 1 << 16;
+
+/// An element which can be stored on disk, in little-endian byte order. The
+/// zero element must be stored as zero bytes.
+pub trait DiskElement: Num + Copy + ToBytes + FromBytes<Bytes = <Self as ToBytes>::Bytes> {}
+
+impl<E: Num + Copy + ToBytes + FromBytes<Bytes = <E as ToBytes>::Bytes>> DiskElement for E {}
+
+/// A square [Band Matrix](https://en.wikipedia.org/wiki/Band_matrix) with L
+/// elements below/left of the main diagonal and U elements above/right of the
+/// main diagonal, stored in a file with the same layout as a [`BandMatrix`].
+/// The rows are streamed in chunks of `CHUNK_ROWS` (65536) rows, so only
+/// `O(CHUNK_ROWS * (L + U))` elements are kept in memory at a time and the
+/// matrix can be much larger than RAM.
+///
+/// [`BandMatrix`]: crate::BandMatrix
+pub struct DiskBandMatrix<E, const L: usize, const U: usize> {
+	n: usize,
+	store: Store<E>,
+}
+
+/// The LU factors of a square Band Matrix stored in a file, with the L
+/// matrix's diagonal elements implied to be 1 and not stored
+pub struct DiskLuMatrices<E, const L: usize, const U: usize>(DiskBandMatrix<E, L, U>);
+
+/// A column vector stored in a file
+pub struct DiskVector<E> {
+	store: Store<E>,
+}
+
+impl<E: DiskElement, const L: usize, const U: usize> DiskBandMatrix<E, L, U> {
+	/// Create a new zeroed Band Matrix of size n by n in the file at `path`,
+	/// overwriting it if it exists
+	pub fn create(path: impl AsRef<Path>, n: usize) -> io::Result<Self> {
+		Ok(Self {
+			n,
+			store: Store::create(path, n, L + U + 1)?,
+		})
+	}
+
+	/// Open the Band Matrix of size n by n stored in the file at `path`
+	pub fn open(path: impl AsRef<Path>, n: usize) -> io::Result<Self> {
+		Ok(Self {
+			n,
+			store: Store::open(path, n, L + U + 1)?,
+		})
+	}
+
+	/// Create a new Band Matrix of size n by n in the file at `path`, with the
+	/// `(i, j)`th element inside of the bands given by `f(i, j)`
+	pub fn from_fn(
+		path: impl AsRef<Path>,
+		n: usize,
+		mut f: impl FnMut(usize, usize) -> E,
+	) -> io::Result<Self> {
+		let res = Self::create(path, n)?;
+		let width = L + U + 1;
+
+		for first in (0..n).step_by(CHUNK_ROWS) {
+			let count = CHUNK_ROWS.min(n - first);
+			let mut rows = vec![E::zero(); count * width];
+
+			for (r, row) in rows.chunks_exact_mut(width).enumerate() {
+				let i = first + r + 1;
+
+				for j in 1.max(i.saturating_sub(L))..=(i + U).min(n) {
+					row[L + j - i] = f(i, j);
+				}
+			}
+
+			res.store.write(first, &rows)?;
+		}
+
+		Ok(res)
+	}
+
+	pub fn n(&self) -> usize {
+		self.n
+	}
+
+	/// Get the `(i, j)`th element of this matrix
+	pub fn get(&self, i: usize, j: usize) -> io::Result<E> {
+		match self.idx((i, j)) {
+			Some(idx) => Ok(self.store.read(i - 1, 1)?[idx]),
+			None => Ok(E::zero()),
+		}
+	}
+
+	/// Set the `(i, j)`th element of this matrix, which must be inside of the
+	/// bands
+	pub fn set(&mut self, i: usize, j: usize, e: E) -> io::Result<()> {
+		let Some(idx) = self.idx((i, j)) else {
+			panic!("Index {:?} out of bounds for DiskBandMatrix<_, {L}, {U}>({}): can not set zero elements", (i, j), self.n)
+		};
+
+		let mut row = self.store.read(i - 1, 1)?;
+		row[idx] = e;
+		self.store.write(i - 1, &row)
+	}
+
+	/// Get the position of the `(i, j)`th element in row `i`
+	fn idx(&self, index: (usize, usize)) -> Option<usize> {
+		let (i, j) = index;
+
+		assert!(
+			i != 0 && j != 0,
+			"Index {index:?} out of bounds: matrices use 1-based indexing"
+		);
+		assert!(
+			i <= self.n && j <= self.n,
+			"Index {index:?} out of bounds: matrix is {}x{}",
+			self.n,
+			self.n
+		);
+
+		if (i < j && j - i > U) || (i > j && i - j > L) {
+			None
+		} else {
+			Some(L + j - i)
+		}
+	}
+
+	/// Perform in-place LU decomposition of this matrix, with the same
+	/// assumptions and results as [`BandMatrix::lu_decompose`]. The rows are
+	/// processed in order, and every row only depends on the `L` rows above
+	/// it, so only a chunk of rows and a ring buffer of the last `L` factorized
+	/// rows are kept in memory.
+	///
+	/// [`BandMatrix::lu_decompose`]: crate::BandMatrix::lu_decompose
+	pub fn lu_decompose(self) -> io::Result<DiskLuMatrices<E, L, U>> {
+		let n = self.n;
+		let width = L + U + 1;
+		// The last `L` rows of the factors, with row $t$ at position `(t - 1) % L`
+		let mut window = vec![E::zero(); L * width];
+
+		for first in (0..n).step_by(CHUNK_ROWS) {
+			let count = CHUNK_ROWS.min(n - first);
+			let mut rows = self.store.read(first, count)?;
+
+			for (r, row) in rows.chunks_exact_mut(width).enumerate() {
+				let k = first + r + 1;
+
+				// The `(t, c)`th element of the factors for $k - L \le t < k$
+				let get = |t: usize, c: usize| window[(t - 1) % L * width + L + c - t];
+
+				// $l_{k,j} = \frac{a_{k,j} - \sum_{t=\max(1, k - L, j - U)}^{j-1} l_{k,t} u_{t,j}}{u_{j,j}}$ for $j = \max(1, k - L), ..., k - 1$
+				for j in 1.max(k.saturating_sub(L))..k {
+					let sum = (1.max(k.saturating_sub(L)).max(j.saturating_sub(U))..j)
+						.map(|t| row[L + t - k] * get(t, j))
+						.reduce(Add::add)
+						.unwrap_or_else(E::zero);
+
+					row[L + j - k] = (row[L + j - k] - sum) / get(j, j);
+				}
+
+				// $u_{k,m} = a_{k,m} - \sum_{j=\max(1, k - L, m - U)}^{k-1} l_{k,j} u_{j,m}$ for $m = k, ..., k + U \le n$
+				for m in k..=(k + U).min(n) {
+					let sum = (1.max(k.saturating_sub(L)).max(m.saturating_sub(U))..k)
+						.map(|j| row[L + j - k] * get(j, m))
+						.reduce(Add::add)
+						.unwrap_or_else(E::zero);
+
+					row[L + m - k] = row[L + m - k] - sum;
+				}
+
+				if L > 0 {
+					window[(k - 1) % L * width..][..width].copy_from_slice(row);
+				}
+			}
+
+			self.store.write(first, &rows)?;
+		}
+
+		Ok(DiskLuMatrices(self))
+	}
+}
+
+impl<E: DiskElement, const L: usize, const U: usize> DiskLuMatrices<E, L, U> {
+	/// Open the LU factors of a Band Matrix of size n by n stored in the file
+	/// at `path` by [`DiskBandMatrix::lu_decompose`]
+	pub fn open(path: impl AsRef<Path>, n: usize) -> io::Result<Self> {
+		Ok(Self(DiskBandMatrix::open(path, n)?))
+	}
+
+	/// Get the `(i, j)`th index of the lower matrix
+	pub fn l(&self, i: usize, j: usize) -> io::Result<E> {
+		match i.cmp(&j) {
+			Ordering::Equal => Ok(E::one()),
+			Ordering::Less => Ok(E::zero()),
+			Ordering::Greater => self.0.get(i, j),
+		}
+	}
+
+	/// Get the `(i, j)`th index of the upper matrix
+	pub fn u(&self, i: usize, j: usize) -> io::Result<E> {
+		if i > j {
+			Ok(E::zero())
+		} else {
+			self.0.get(i, j)
+		}
+	}
+
+	pub fn det(&self) -> io::Result<E> {
+		let mut res = E::one();
+
+		for first in (0..self.0.n).step_by(CHUNK_ROWS) {
+			let rows = self.0.store.read(first, CHUNK_ROWS.min(self.0.n - first))?;
+
+			for row in rows.chunks_exact(L + U + 1) {
+				res = res * row[L];
+			}
+		}
+
+		Ok(res)
+	}
+
+	/// Solve $LUx = b$ for $x$, streaming the factors from the file
+	pub fn solve(&self, b: &Vector<E>) -> io::Result<Vector<E>> {
+		let mut x = Vector::from_iter((1..=b.n()).map(|i| b[i]));
+		self.substitute(&mut x)?;
+		Ok(x)
+	}
+
+	/// Solve $LUx = b$ for $x$, overwriting `b` with `x`, streaming both the
+	/// factors and the vector from their files
+	pub fn solve_in_place(&self, b: &mut DiskVector<E>) -> io::Result<()> {
+		self.substitute(&mut b.store)
+	}
+
+	fn substitute(&self, b: &mut impl Rows<E>) -> io::Result<()> {
+		let n = self.0.n;
+		let width = L + U + 1;
+
+		assert_eq!(
+			n,
+			b.len(),
+			"Can't solve system of equations for a matrix and vector with different heights"
+		);
+
+		// $Ly = b$
+		// $y_m = b_m - \sum_{i=\max(1, m - L)}^{m-1} l_{m,i} y_i$ for $m = 1, ..., n$
+		let mut previous: Vec<E> = Vec::with_capacity(L);
+
+		for first in (0..n).step_by(CHUNK_ROWS) {
+			let count = CHUNK_ROWS.min(n - first);
+			let rows = self.0.store.read(first, count)?;
+
+			// The last `L` elements of $y$ before this chunk, followed by it
+			let mut y = previous;
+			let offset = first - y.len();
+			y.extend(b.read(first, count)?);
+
+			for (r, row) in rows.chunks_exact(width).enumerate() {
+				let m = first + r + 1;
+
+				y[m - 1 - offset] = y[m - 1 - offset]
+					- (1.max(m.saturating_sub(L))..=m - 1)
+						.map(|i| row[L + i - m] * y[i - 1 - offset])
+						.reduce(Add::add)
+						.unwrap_or_else(E::zero);
+			}
+
+			b.write(first, &y[first - offset..])?;
+			previous = y[y.len() - L.min(y.len())..].to_vec();
+		}
+
+		// $Ux = y$
+		// $x_m = \frac{y_m - \sum_{i=m+1}^{\min(n, m + U)} u_{m,i} x_i}{u_{m,m}}$ for $m = n, ..., 1$
+		let mut next: Vec<E> = Vec::with_capacity(U);
+
+		for first in (0..n).step_by(CHUNK_ROWS).rev() {
+			let count = CHUNK_ROWS.min(n - first);
+			let rows = self.0.store.read(first, count)?;
+
+			// This chunk of $x$, followed by the first `U` elements after it
+			let mut x = b.read(first, count)?;
+			x.extend(next);
+
+			for (r, row) in rows.chunks_exact(width).enumerate().rev() {
+				let m = first + r + 1;
+
+				x[m - 1 - first] = (x[m - 1 - first]
+					- (m + 1..=n.min(m + U))
+						.map(|i| row[L + i - m] * x[i - 1 - first])
+						.reduce(Add::add)
+						.unwrap_or_else(E::zero))
+					/ row[L];
+			}
+
+			b.write(first, &x[..count])?;
+			next = x[..U.min(count)].to_vec();
+		}
+
+		Ok(())
+	}
+}
+
+impl<E: DiskElement> DiskVector<E> {
+	/// Create a new zeroed vector of length n in the file at `path`,
+	/// overwriting it if it exists
+	pub fn create(path: impl AsRef<Path>, n: usize) -> io::Result<Self> {
+		Ok(Self {
+			store: Store::create(path, n, 1)?,
+		})
+	}
+
+	/// Open the vector of length n stored in the file at `path`
+	pub fn open(path: impl AsRef<Path>, n: usize) -> io::Result<Self> {
+		Ok(Self {
+			store: Store::open(path, n, 1)?,
+		})
+	}
+
+	/// Create a new vector of length n in the file at `path`, with the `i`th
+	/// element given by `f(i)`
+	pub fn from_fn(
+		path: impl AsRef<Path>,
+		n: usize,
+		mut f: impl FnMut(usize) -> E,
+	) -> io::Result<Self> {
+		let res = Self::create(path, n)?;
+
+		for first in (0..n).step_by(CHUNK_ROWS) {
+			let count = CHUNK_ROWS.min(n - first);
+			let elements: Vec<E> = (first + 1..=first + count).map(&mut f).collect();
+			res.store.write(first, &elements)?;
+		}
+
+		Ok(res)
+	}
+
+	pub fn n(&self) -> usize {
+		self.store.rows
+	}
+
+	/// Get the `i`th element of this vector
+	pub fn get(&self, i: usize) -> io::Result<E> {
+		assert_ne!(i, 0, "Index out of bounds: Vectors use 1-based indexing");
+
+		Ok(self.store.read(i - 1, 1)?[0])
+	}
+
+	/// Read the whole vector into memory
+	pub fn to_vector(&self) -> io::Result<Vector<E>> {
+		Ok(Vector::from_iter(self.store.read(0, self.store.rows)?))
+	}
+}
+
+/// Rows of a fixed number of elements, which can be read and written in
+/// chunks (using 0-based row indices)
+trait Rows<E> {
+	fn len(&self) -> usize;
+
+	fn read(&self, first: usize, count: usize) -> io::Result<Vec<E>>;
+
+	fn write(&mut self, first: usize, elements: &[E]) -> io::Result<()>;
+}
+
+/// The elements of a matrix or vector stored in a file, as `rows` rows of
+/// `width` elements
+struct Store<E> {
+	file: File,
+	rows: usize,
+	width: usize,
+	_marker: PhantomData<E>,
+}
+
+impl<E: DiskElement> Store<E> {
+	fn create(path: impl AsRef<Path>, rows: usize, width: usize) -> io::Result<Self> {
+		let file = File::options()
+			.read(true)
+			.write(true)
+			.create(true)
+			.truncate(true)
+			.open(path)?;
+
+		// The file is filled with zero bytes, which are zero elements
+		file.set_len((rows * width * Self::element_size()) as u64)?;
+
+		Ok(Self {
+			file,
+			rows,
+			width,
+			_marker: PhantomData,
+		})
+	}
+
+	fn open(path: impl AsRef<Path>, rows: usize, width: usize) -> io::Result<Self> {
+		let file = File::options().read(true).write(true).open(path)?;
+		let expected = (rows * width * Self::element_size()) as u64;
+
+		if file.metadata()?.len() != expected {
+			return Err(Error::new(
+				ErrorKind::InvalidData,
+				format!("Expected a file of {expected} bytes"),
+			));
+		}
+
+		Ok(Self {
+			file,
+			rows,
+			width,
+			_marker: PhantomData,
+		})
+	}
+
+	fn element_size() -> usize {
+		E::zero().to_le_bytes().as_ref().len()
+	}
+
+	fn seek(&self, row: usize) -> io::Result<()> {
+		let offset = row * self.width * Self::element_size();
+		(&self.file)
+			.seek(SeekFrom::Start(offset as u64))
+			.map(|_| ())
+	}
+
+	fn read(&self, first: usize, count: usize) -> io::Result<Vec<E>> {
+		let size = Self::element_size();
+		let mut bytes = vec![0; count * self.width * size];
+
+		self.seek(first)?;
+		(&self.file).read_exact(&mut bytes)?;
+
+		Ok(bytes
+			.chunks_exact(size)
+			.map(|chunk| {
+				let mut bytes = E::zero().to_le_bytes();
+				bytes.as_mut().copy_from_slice(chunk);
+				E::from_le_bytes(&bytes)
+			})
+			.collect())
+	}
+
+	fn write(&self, first: usize, elements: &[E]) -> io::Result<()> {
+		let bytes: Vec<u8> = elements
+			.iter()
+			.flat_map(|e| e.to_le_bytes().as_ref().to_vec())
+			.collect();
+
+		self.seek(first)?;
+		(&self.file).write_all(&bytes)
+	}
+}
+
+impl<E: DiskElement> Rows<E> for Store<E> {
+	fn len(&self) -> usize {
+		self.rows
+	}
+
+	fn read(&self, first: usize, count: usize) -> io::Result<Vec<E>> {
+		Store::read(self, first, count)
+	}
+
+	fn write(&mut self, first: usize, elements: &[E]) -> io::Result<()> {
+		Store::write(self, first, elements)
+	}
+}
+
+impl<E: Copy> Rows<E> for Vector<E> {
+	fn len(&self) -> usize {
+		self.n()
+	}
+
+	fn read(&self, first: usize, count: usize) -> io::Result<Vec<E>> {
+		Ok((first + 1..=first + count).map(|i| self[i]).collect())
+	}
+
+	fn write(&mut self, first: usize, elements: &[E]) -> io::Result<()> {
+		for (i, &e) in elements.iter().enumerate() {
+			self[first + i + 1] = e;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{env, fs, path::PathBuf};
+
+	use super::*;
+
+	/// A temporary file, which is removed when dropped
+	struct TempFile(PathBuf);
+
+	impl TempFile {
+		fn new(name: &str) -> Self {
+			Self(env::temp_dir().join(format!("band-{}-{name}", std::process::id())))
+		}
+	}
+
+	impl Drop for TempFile {
+		fn drop(&mut self) {
+			let _ = fs::remove_file(&self.0);
+		}
+	}
+
+	#[test]
+	fn matches_band_matrix() {
+		// Spans several chunks, so that the windows cross chunk boundaries
+		let n = 2 * CHUNK_ROWS + 123;
+		let a = crate::test_matrices::num3_matrix(n);
+		let matrix_file = TempFile::new("matrix");
+		let vector_file = TempFile::new("vector");
+
+		let disk =
+			DiskBandMatrix::<f64, 1, 2>::from_fn(&matrix_file.0, n, |i, j| a[(i, j)]).unwrap();
+		assert_eq!(disk.get(5, 6).unwrap(), a[(5, 6)]);
+		assert_eq!(disk.get(6, 5).unwrap(), a[(6, 5)]);
+		assert_eq!(disk.get(1, 4).unwrap(), 0.0);
+
+		let lu = a.lu_decompose();
+		let disk = disk.lu_decompose().unwrap();
+
+		for (i, j) in [
+			(1, 1),
+			(2, 1),
+			(1, 3),
+			(CHUNK_ROWS, CHUNK_ROWS + 1),
+			(CHUNK_ROWS + 1, CHUNK_ROWS),
+			(n, n),
+		] {
+			assert_eq!(disk.l(i, j).unwrap(), *lu.l(i, j));
+			assert_eq!(disk.u(i, j).unwrap(), *lu.u(i, j));
+		}
+
+		assert_eq!(disk.det().unwrap(), lu.det());
+
+		let b = crate::test_matrices::num3_vector(n);
+		let x = lu.solve(&b);
+		assert!(disk.solve(&b).unwrap() == x);
+
+		let mut disk_b = DiskVector::from_fn(&vector_file.0, n, |i| i as f64).unwrap();
+		disk.solve_in_place(&mut disk_b).unwrap();
+		assert!(disk_b.to_vector().unwrap() == x);
+
+		// The factors persist in the file
+		drop(disk);
+		let reopened = DiskLuMatrices::<f64, 1, 2>::open(&matrix_file.0, n).unwrap();
+		assert_eq!(reopened.u(n, n).unwrap(), *lu.u(n, n));
+		assert!(DiskLuMatrices::<f64, 2, 2>::open(&matrix_file.0, n).is_err());
+	}
+
+	#[test]
+	fn set_elements() {
+		let file = TempFile::new("set");
+		let mut a = DiskBandMatrix::<f64, 1, 1>::create(&file.0, 2).unwrap();
+
+		a.set(1, 1, 4.0).unwrap();
+		a.set(1, 2, 3.0).unwrap();
+		a.set(2, 1, 6.0).unwrap();
+		a.set(2, 2, 3.0).unwrap();
+
+		let a = a.lu_decompose().unwrap();
+
+		assert_eq!(a.u(1, 1).unwrap(), 4.0);
+		assert_eq!(a.u(1, 2).unwrap(), 3.0);
+		assert_eq!(a.l(2, 1).unwrap(), 1.5);
+		assert_eq!(a.u(2, 2).unwrap(), -1.5);
+	}
+}
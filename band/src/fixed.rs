@@ -0,0 +1,391 @@
+use core::{
+	array,
+	ops::{Add, Index, IndexMut, Mul},
+};
+
+use num_traits::Num;
+
+use crate::matrix::BandMatrix;
+
+/// A square N by N Band Matrix with L elements below/left of the main diagonal
+/// and U elements above/right of the main diagonal, which stores its elements
+/// in arrays and thus needs no heap allocation. This is meant for small
+/// systems (e.g. on embedded targets); indexing works like in
+/// [`BandMatrix`], including the panic on mutable indexing outside of the
+/// bands.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FixedBandMatrix<E, const N: usize, const L: usize, const U: usize> {
+	zero: E,
+	/// `lower[d - 1][i - 1]` is $a_{i,i-d}$
+	lower: [[E; N]; L],
+	/// `diagonal[i - 1]` is $a_{i,i}$
+	diagonal: [E; N],
+	/// `upper[d - 1][i - 1]` is $a_{i,i+d}$
+	upper: [[E; N]; U],
+}
+
+/// The LU factors of a [`FixedBandMatrix`], stored like the LU factors of a
+/// [`BandMatrix`]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FixedLuMatrices<E, const N: usize, const L: usize, const U: usize>(
+	FixedBandMatrix<E, N, L, U>,
+	E,
+);
+
+impl<E, const N: usize, const L: usize, const U: usize> FixedBandMatrix<E, N, L, U> {
+	/// Get the size of the matrix, which is always `N`
+	pub fn n(&self) -> usize {
+		N
+	}
+
+	fn get(&self, index: (usize, usize)) -> Option<&E> {
+		let (i, j) = Self::check(index);
+
+		if i == j {
+			Some(&self.diagonal[i - 1])
+		} else if i > j {
+			self.lower.get(i - j - 1).map(|d| &d[i - 1])
+		} else {
+			self.upper.get(j - i - 1).map(|d| &d[i - 1])
+		}
+	}
+
+	fn get_mut(&mut self, index: (usize, usize)) -> Option<&mut E> {
+		let (i, j) = Self::check(index);
+
+		if i == j {
+			Some(&mut self.diagonal[i - 1])
+		} else if i > j {
+			self.lower.get_mut(i - j - 1).map(|d| &mut d[i - 1])
+		} else {
+			self.upper.get_mut(j - i - 1).map(|d| &mut d[i - 1])
+		}
+	}
+
+	fn check(index: (usize, usize)) -> (usize, usize) {
+		assert!(
+			index.0 != 0 && index.1 != 0,
+			"Index {index:?} out of bounds: matrices use 1-based indexing"
+		);
+		assert!(
+			index.0 <= N && index.1 <= N,
+			"Index {index:?} out of bounds: matrix is {N}x{N}"
+		);
+
+		index
+	}
+}
+
+impl<E: Num, const N: usize, const L: usize, const U: usize> FixedBandMatrix<E, N, L, U> {
+	/// Create a new zeroed Band Matrix of size N by N
+	pub fn new() -> Self {
+		Self {
+			zero: E::zero(),
+			lower: array::from_fn(|_| array::from_fn(|_| E::zero())),
+			diagonal: array::from_fn(|_| E::zero()),
+			upper: array::from_fn(|_| array::from_fn(|_| E::zero())),
+		}
+	}
+
+	/// Create an N by N Band Matrix with $a_{i,j} = f(i, j)$ inside of the
+	/// bands. `f` is called only inside of the bands, with 1-based indices.
+	pub fn from_fn(mut f: impl FnMut(usize, usize) -> E) -> Self {
+		let mut res = Self::new();
+
+		for i in 1..=N {
+			for j in 1.max(i.saturating_sub(L))..=(i + U).min(N) {
+				res[(i, j)] = f(i, j);
+			}
+		}
+
+		res
+	}
+}
+
+impl<E: Num, const N: usize, const L: usize, const U: usize> Default
+	for FixedBandMatrix<E, N, L, U>
+{
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<E: Num + Copy, const N: usize, const L: usize, const U: usize> FixedBandMatrix<E, N, L, U> {
+	/// Copy this matrix into a heap-allocated band matrix
+	pub fn to_band(&self) -> BandMatrix<E, L, U> {
+		BandMatrix::from_fn(N, |i, j| self[(i, j)])
+	}
+
+	/// $\sum_{j \in \{lo, ..., hi\}} a_{i,j}x_j$
+	fn row_sum(&self, i: usize, lo: usize, hi: usize, x: &[E; N]) -> E {
+		(lo..=hi)
+			.map(|j| self[(i, j)] * x[j - 1])
+			.reduce(Add::add)
+			.unwrap_or_else(E::zero)
+	}
+
+	/// Perform one Gauss-Seidel iteration, returning the next `x`
+	pub fn gauss_seidel_iteration(&self, x: &[E; N], b: &[E; N]) -> [E; N] {
+		let mut next = [E::zero(); N];
+
+		for i in 1..=N {
+			// $x_i^{(k+1)} = \frac{1}{a_{i,i}} (b_i - \sum_{j = 1}^{i - 1} a_{i,j}x_j^{(k + 1)} - \sum_{j = i + 1}^n a_{i,j}x_j^{(k)})$
+			next[i - 1] = (b[i - 1]
+				- self.row_sum(i, 1.max(i.saturating_sub(L)), i - 1, &next)
+				- self.row_sum(i, i + 1, (i + U).min(N), x))
+				/ self[(i, i)];
+		}
+
+		next
+	}
+
+	/// Perform one Jacobi iteration, returning the next `x`
+	pub fn jacobi_iteration(&self, x: &[E; N], b: &[E; N]) -> [E; N] {
+		array::from_fn(|i| {
+			let i = i + 1;
+
+			// $x_i^{(k+1)} = \frac{1}{a_{i,i}} (b_i - \sum_{j \ne i} a_{i,j}x_j^{(k)})$
+			(b[i - 1]
+				- self.row_sum(i, 1.max(i.saturating_sub(L)), i - 1, x)
+				- self.row_sum(i, i + 1, (i + U).min(N), x))
+				/ self[(i, i)]
+		})
+	}
+
+	/// Perform LU decomposition of this matrix in place, with the same
+	/// assumptions as [`BandMatrix::lu_decompose`]
+	pub fn lu_decompose(mut self) -> FixedLuMatrices<E, N, L, U> {
+		for k in 1..=N {
+			// $u_{k,m} = a_{k,m} - \sum_{j=\max(1, k - L, m - U)}^{k-1} l_{k,j} u_{j,m}$ for $m = k, k + 1, ..., k + U \le n$
+			for m in k..=(k + U).min(N) {
+				self[(k, m)] = self[(k, m)]
+					- (1.max(k.saturating_sub(L)).max(m.saturating_sub(U))..=k - 1)
+						.map(|j| self[(k, j)] * self[(j, m)])
+						.reduce(Add::add)
+						.unwrap_or_else(E::zero);
+			}
+
+			// $l_{i,k} = \frac{(a_{i,k} - \sum_{j=\max(1, i - L, k - U)}^{k-1} l_{i,j} u_{j,k})}{u_{k,k}}$ for $i = k + 1, k + 2, ..., k + L \le n$
+			for i in k + 1..=(k + L).min(N) {
+				self[(i, k)] = (self[(i, k)]
+					- (1.max(i.saturating_sub(L)).max(k.saturating_sub(U))..=k - 1)
+						.map(|j| self[(i, j)] * self[(j, k)])
+						.reduce(Add::add)
+						.unwrap_or_else(E::zero))
+					/ self[(k, k)];
+			}
+		}
+
+		FixedLuMatrices(self, E::one())
+	}
+}
+
+impl<E, const N: usize, const L: usize, const U: usize> FixedLuMatrices<E, N, L, U> {
+	/// Get the `(i, j)`th index of the lower matrix
+	pub fn l(&self, i: usize, j: usize) -> &E {
+		if i == j {
+			&self.1
+		} else if i < j {
+			&self.0.zero
+		} else {
+			&self.0[(i, j)]
+		}
+	}
+
+	/// Get the `(i, j)`th index of the upper matrix
+	pub fn u(&self, i: usize, j: usize) -> &E {
+		if i > j {
+			&self.0.zero
+		} else {
+			&self.0[(i, j)]
+		}
+	}
+}
+
+impl<E: Num + Copy, const N: usize, const L: usize, const U: usize> FixedLuMatrices<E, N, L, U> {
+	pub fn det(&self) -> E {
+		self.0.diagonal.iter().fold(E::one(), |res, &u| res * u)
+	}
+
+	pub fn solve(&self, b: &[E; N]) -> [E; N] {
+		let mut x = *b;
+		self.solve_in_place(&mut x);
+		x
+	}
+
+	/// Solve $Ax = b$ for $x$, overwriting `b` with `x`
+	pub fn solve_in_place(&self, b: &mut [E; N]) {
+		// $Ly = b$
+		// $y_m = b_m - \sum_{i=\max(1, m - L)}^{m-1} l_{m,i} y_i$ for $m = 1, ..., n$
+		for m in 1..=N {
+			b[m - 1] = b[m - 1]
+				- (1.max(m.saturating_sub(L))..=m - 1)
+					.map(|i| *self.l(m, i) * b[i - 1])
+					.reduce(Add::add)
+					.unwrap_or_else(E::zero);
+		}
+
+		// $Ux = y$
+		// $x_m = \frac{y_m - \sum_{i=m+1}^{\min(n, m + U)} u_{m,i} x_i}{u_{m,m}}$ for $m = n, ..., 1$
+		for m in (1..=N).rev() {
+			b[m - 1] = (b[m - 1]
+				- (m + 1..=N.min(m + U))
+					.map(|i| *self.u(m, i) * b[i - 1])
+					.reduce(Add::add)
+					.unwrap_or_else(E::zero))
+				/ *self.u(m, m);
+		}
+	}
+}
+
+impl<E, const N: usize, const L: usize, const U: usize> Index<(usize, usize)>
+	for FixedBandMatrix<E, N, L, U>
+{
+	type Output = E;
+
+	fn index(&self, index: (usize, usize)) -> &Self::Output {
+		self.get(index).unwrap_or(&self.zero)
+	}
+}
+
+impl<E, const N: usize, const L: usize, const U: usize> IndexMut<(usize, usize)>
+	for FixedBandMatrix<E, N, L, U>
+{
+	fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+		self.get_mut(index).unwrap_or_else(|| {
+			panic!("Index {index:?} out of bounds for FixedBandMatrix<_, {N}, {L}, {U}>: mutable indexing can not access zero elements")
+		})
+	}
+}
+
+impl<E, const N: usize, const L: usize, const U: usize> Index<(usize, usize)>
+	for FixedLuMatrices<E, N, L, U>
+{
+	type Output = E;
+
+	fn index(&self, index: (usize, usize)) -> &Self::Output {
+		&self.0[index]
+	}
+}
+
+impl<E: Num + Copy, const N: usize, const L: usize, const U: usize> Mul<&[E; N]>
+	for &FixedBandMatrix<E, N, L, U>
+{
+	type Output = [E; N];
+
+	fn mul(self, rhs: &[E; N]) -> Self::Output {
+		// $(Ax)_i = \sum_{j=\max(1, i - L)}^{\min(n, i + U)} a_{i,j} x_j$
+		array::from_fn(|i| {
+			self.row_sum(
+				i + 1,
+				1.max((i + 1).saturating_sub(L)),
+				(i + 1 + U).min(N),
+				rhs,
+			)
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::vector::Vector;
+
+	fn assert_close<const N: usize>(x: &[f64; N], expected: &Vector<f64>) {
+		for i in 1..=N {
+			assert!((x[i - 1] - expected[i]).abs() < 1e-12);
+		}
+	}
+
+	#[test]
+	fn tridiagonal() {
+		let mut a = FixedBandMatrix::<f64, 8, 1, 1>::new();
+
+		for i in 1..=8 {
+			a[(i, i)] = 4.0;
+
+			if i > 1 {
+				a[(i, i - 1)] = -1.0;
+				a[(i - 1, i)] = -1.0 / i as f64;
+			}
+		}
+
+		let b = array::from_fn(|i| (i + 1) as f64);
+		let band = a.to_band();
+		let expected = band.clone().lu_decompose().solve(&Vector::from_iter(b));
+
+		assert_eq!(a[(1, 3)], 0.0);
+		assert_close(&a.lu_decompose().solve(&b), &expected);
+		assert_close(&(&a * &b), &(&band * &Vector::from_iter(b)));
+		assert!((a.lu_decompose().det() - band.lu_decompose().det()).abs() < 1e-9);
+	}
+
+	#[test]
+	fn pentadiagonal() {
+		let band = crate::test_matrices::num5_matrix(10);
+		let a = FixedBandMatrix::<f64, 10, 2, 2>::from_fn(|i, j| band[(i, j)]);
+
+		assert!(a.to_band() == band);
+
+		let b = array::from_fn(|i| (i + 1) as f64);
+		let expected = band
+			.clone()
+			.lu_decompose()
+			.solve(&crate::test_matrices::num5_b(10));
+		assert_close(&a.lu_decompose().solve(&b), &expected);
+
+		// Both iterations converge to the same solution as the LU solve
+		let (mut jacobi, mut gauss_seidel) = ([0.0; 10], [0.0; 10]);
+
+		for _ in 0..100 {
+			jacobi = a.jacobi_iteration(&jacobi, &b);
+			gauss_seidel = a.gauss_seidel_iteration(&gauss_seidel, &b);
+		}
+
+		assert_close(&jacobi, &expected);
+		assert_close(&gauss_seidel, &expected);
+	}
+
+	#[test]
+	fn matches_band_matrix() {
+		let band = crate::test_matrices::num3_matrix(12);
+		let a = FixedBandMatrix::<f64, 12, 1, 2>::from_fn(|i, j| band[(i, j)]);
+
+		assert!(a.to_band() == band);
+
+		let b: [f64; 12] = array::from_fn(|i| (i + 1) as f64);
+		let band_b = Vector::from_iter(b);
+
+		let lu = a.lu_decompose();
+		let band_lu = band.clone().lu_decompose();
+
+		for i in 1..=12 {
+			for j in 1..=12 {
+				assert!((lu.l(i, j) - band_lu.l(i, j)).abs() < 1e-14);
+				assert!((lu.u(i, j) - band_lu.u(i, j)).abs() < 1e-14);
+			}
+		}
+
+		assert_close(&lu.solve(&b), &band_lu.solve(&band_b));
+
+		// Every step of the iterations is the same, not only the limit
+		let (mut jacobi, mut band_jacobi) = ([0.0; 12], Vector::new(12));
+		let (mut gauss_seidel, mut band_gauss_seidel) = ([0.0; 12], Vector::new(12));
+
+		for _ in 0..10 {
+			jacobi = a.jacobi_iteration(&jacobi, &b);
+			band_jacobi = band.jacobi_iteration(&band_jacobi, &band_b);
+			assert_close(&jacobi, &band_jacobi);
+
+			gauss_seidel = a.gauss_seidel_iteration(&gauss_seidel, &b);
+			band_gauss_seidel = band.gauss_seidel_iteration(&band_gauss_seidel, &band_b);
+			assert_close(&gauss_seidel, &band_gauss_seidel);
+		}
+	}
+
+	#[test]
+	#[should_panic]
+	fn mutable_index_outside_band() {
+		FixedBandMatrix::<f64, 4, 1, 1>::new()[(1, 3)] = 1.0;
+	}
+}
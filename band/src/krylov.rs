@@ -0,0 +1,458 @@
+use alloc::{vec, vec::Vec};
+
+use num_traits::real::Real;
+
+use crate::{
+	convergence::{Convergence, Criteria},
+	operator::LinearOperator,
+	preconditioner::Preconditioner,
+	vector::Vector,
+};
+
+/// The state of the (preconditioned) Conjugate Gradient method, which can be
+/// iterated to get the consecutive approximations of the solution
+pub struct ConjugateGradient<'a, E, A: ?Sized, P> {
+	a: &'a A,
+	preconditioner: &'a P,
+	x: Vector<E>,
+	r: Vector<E>,
+	p: Vector<E>,
+	rz: E,
+}
+
+impl<'a, E: Real, A: LinearOperator<E> + ?Sized, P: Preconditioner<E>>
+	ConjugateGradient<'a, E, A, P>
+{
+	pub(crate) fn new(a: &'a A, x: Vector<E>, b: &Vector<E>, preconditioner: &'a P) -> Self {
+		assert_eq!(a.n(), x.n());
+		assert_eq!(x.n(), b.n());
+
+		// $r_0 = b - Ax_0$, $z_0 = M^{-1}r_0$, $p_0 = z_0$
+		let r = a.residual(&x, b);
+		let z = preconditioner.apply(&r);
+		let rz = r.dot(&z);
+
+		Self {
+			a,
+			preconditioner,
+			x,
+			r,
+			p: z,
+			rz,
+		}
+	}
+
+	/// Get the current approximation of the solution
+	pub fn x(&self) -> &Vector<E> {
+		&self.x
+	}
+
+	/// Get the norm of the current residual $||b - Ax^{(k)}||$
+	pub fn residual(&self) -> E {
+		self.r.norm()
+	}
+}
+
+impl<E: Real, A: LinearOperator<E> + ?Sized, P: Preconditioner<E>> Iterator
+	for ConjugateGradient<'_, E, A, P>
+{
+	type Item = Vector<E>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let q = self.a.matvec(&self.p);
+		let pq = self.p.dot(&q);
+
+		// The method breaks down once the residual is exactly zero (or if the
+		// matrix isn't positive-definite)
+		if pq == E::zero() || self.rz == E::zero() {
+			return None;
+		}
+
+		// $α_k = \frac{r_k^T z_k}{p_k^T A p_k}$
+		let alpha = self.rz / pq;
+
+		// $x_{k+1} = x_k + α_k p_k$, $r_{k+1} = r_k - α_k A p_k$
+		self.x.axpy(alpha, &self.p);
+		self.r.axpy(-alpha, &q);
+
+		// $z_{k+1} = M^{-1}r_{k+1}$, $β_k = \frac{r_{k+1}^T z_{k+1}}{r_k^T z_k}$
+		let z = self.preconditioner.apply(&self.r);
+		let rz = self.r.dot(&z);
+		let beta = rz / self.rz;
+		self.rz = rz;
+
+		// $p_{k+1} = z_{k+1} + β_k p_k$
+		self.p = z + &self.p * beta;
+
+		Some(self.x.clone())
+	}
+}
+
+/// Perform the preconditioned Conjugate Gradient method. Both the matrix
+/// and the preconditioner must be symmetric positive-definite.
+pub(crate) fn conjugate_gradient<E: Real, A: LinearOperator<E> + ?Sized>(
+	a: &A,
+	x: Vector<E>,
+	b: &Vector<E>,
+	preconditioner: &impl Preconditioner<E>,
+	criteria: Criteria<E>,
+) -> Convergence<E> {
+	let tolerance = criteria.epsilon * b.norm();
+	let mut cg = ConjugateGradient::new(a, x, b, preconditioner);
+	let mut residuals = vec![cg.residual()];
+
+	while cg.residual() > tolerance && residuals.len() <= criteria.max_iters {
+		if cg.next().is_none() {
+			break;
+		}
+
+		residuals.push(cg.residual());
+	}
+
+	Convergence {
+		converged: cg.residual() <= tolerance,
+		x: cg.x,
+		residuals,
+	}
+}
+
+/// Perform the restarted GMRES(m) method with right preconditioning,
+/// restarting every `restart` iterations. Works for any nonsingular matrix
+/// and preconditioner.
+pub(crate) fn gmres<E: Real, A: LinearOperator<E> + ?Sized>(
+	a: &A,
+	mut x: Vector<E>,
+	b: &Vector<E>,
+	restart: usize,
+	preconditioner: &impl Preconditioner<E>,
+	criteria: Criteria<E>,
+) -> Convergence<E> {
+	assert_eq!(a.n(), x.n());
+	assert_eq!(x.n(), b.n());
+	assert_ne!(restart, 0, "GMRES can't restart every 0 iterations");
+
+	let tolerance = criteria.epsilon * b.norm();
+	let mut residuals = vec![a.residual(&x, b).norm()];
+	let mut converged = residuals[0] <= tolerance;
+
+	while !converged && residuals.len() <= criteria.max_iters {
+		// $r_0 = b - Ax_0$, $β = ||r_0||$, $v_1 = \frac{r_0}{β}$
+		let r = a.residual(&x, b);
+		let beta = r.norm();
+
+		if beta <= tolerance {
+			converged = true;
+			break;
+		}
+
+		let mut v = vec![r / beta];
+		let mut h: Vec<Vec<E>> = Vec::with_capacity(restart);
+		let mut givens: Vec<(E, E)> = Vec::with_capacity(restart);
+		let mut g = vec![beta];
+		let mut breakdown = false;
+
+		// Arnoldi process on $AM^{-1}$, reducing the Hessenberg matrix to
+		// upper triangular form with Givens rotations as it's built
+		while h.len() < restart && residuals.len() <= criteria.max_iters {
+			let j = h.len();
+			let mut w = a.matvec(&preconditioner.apply(&v[j]));
+			let mut column = Vec::with_capacity(j + 2);
+
+			// Modified Gram-Schmidt: $h_{i,j} = w^T v_i$, $w = w - h_{i,j} v_i$
+			for vi in &v {
+				let hij = w.dot(vi);
+				w.axpy(-hij, vi);
+				column.push(hij);
+			}
+
+			let norm = w.norm();
+			column.push(norm);
+
+			for (i, &(c, s)) in givens.iter().enumerate() {
+				let (upper, lower) = (column[i], column[i + 1]);
+				column[i] = c * upper + s * lower;
+				column[i + 1] = c * lower - s * upper;
+			}
+
+			// $r = 0$ only if $h_{j+1,j} = 0$ and $AM^{-1}$ is singular on the
+			// Krylov subspace, in which case there is nothing to rotate
+			let r = column[j].hypot(column[j + 1]);
+			let (c, s) = if r == E::zero() {
+				(E::one(), E::zero())
+			} else {
+				(column[j] / r, column[j + 1] / r)
+			};
+			column[j] = r;
+			column[j + 1] = E::zero();
+			givens.push((c, s));
+
+			// The last element of $g$ is the norm of the current residual,
+			// unless row $j$ of $H$ is 0 and $g_j$ can't be reduced
+			g.push(-s * g[j]);
+			g[j] = c * g[j];
+
+			h.push(column);
+			residuals.push(if r == E::zero() { g[j] } else { g[j + 1] }.abs());
+
+			if residuals[residuals.len() - 1] <= tolerance {
+				converged = true;
+				break;
+			}
+
+			// $h_{j+1,j} = 0$ means that the Krylov subspace is invariant under
+			// $AM^{-1}$, so it can't be extended
+			if norm == E::zero() {
+				breakdown = true;
+				break;
+			}
+
+			v.push(w / norm);
+		}
+
+		// Solve the triangular system $Hy = g$ by back substitution, leaving
+		// out the directions with a zero row
+		let k = h.len();
+		let mut y = vec![E::zero(); k];
+
+		for i in (0..k).rev() {
+			if h[i][i] != E::zero() {
+				y[i] = (i + 1..k).fold(g[i], |acc, l| acc - h[l][i] * y[l]) / h[i][i];
+			}
+		}
+
+		// $x = x_0 + M^{-1} \sum_i y_i v_i$
+		let update = y
+			.iter()
+			.zip(&v)
+			.fold(Vector::new(x.n()), |mut acc, (&y, v)| {
+				acc.axpy(y, v);
+				acc
+			});
+		x += preconditioner.apply(&update);
+
+		if breakdown {
+			break;
+		}
+	}
+
+	Convergence {
+		x,
+		residuals,
+		converged,
+	}
+}
+
+/// Perform the BiCGSTAB method with right preconditioning. Works for any
+/// nonsingular matrix and preconditioner, but may break down before
+/// converging.
+pub(crate) fn bicgstab<E: Real, A: LinearOperator<E> + ?Sized>(
+	a: &A,
+	mut x: Vector<E>,
+	b: &Vector<E>,
+	preconditioner: &impl Preconditioner<E>,
+	criteria: Criteria<E>,
+) -> Convergence<E> {
+	assert_eq!(a.n(), x.n());
+	assert_eq!(x.n(), b.n());
+
+	let tolerance = criteria.epsilon * b.norm();
+
+	// $r_0 = b - Ax_0$, $\hat{r}_0 = r_0$
+	let mut r = a.residual(&x, b);
+	let r_hat = r.clone();
+	let mut residuals = vec![r.norm()];
+
+	let (mut rho, mut alpha, mut omega) = (E::one(), E::one(), E::one());
+	let mut v = Vector::new(x.n());
+	let mut p = Vector::new(x.n());
+
+	while residuals[residuals.len() - 1] > tolerance && residuals.len() <= criteria.max_iters {
+		// $ρ_k = \hat{r}_0^T r_{k-1}$, $β = \frac{ρ_k}{ρ_{k-1}} \frac{α}{ω}$
+		let rho_next = r_hat.dot(&r);
+
+		if rho_next == E::zero() {
+			break;
+		}
+
+		let beta = (rho_next / rho) * (alpha / omega);
+		rho = rho_next;
+
+		// $p_k = r_{k-1} + β(p_{k-1} - ωv_{k-1})$, $v_k = AM^{-1}p_k$
+		p.axpy(-omega, &v);
+		p = &r + p * beta;
+		let p_hat = preconditioner.apply(&p);
+		v = a.matvec(&p_hat);
+
+		let r_hat_v = r_hat.dot(&v);
+
+		if r_hat_v == E::zero() {
+			break;
+		}
+
+		// $α = \frac{ρ_k}{\hat{r}_0^T v_k}$, $s = r_{k-1} - αv_k$
+		alpha = rho / r_hat_v;
+		let mut s = r.clone();
+		s.axpy(-alpha, &v);
+
+		if s.norm() <= tolerance {
+			x.axpy(alpha, &p_hat);
+			r = s;
+			residuals.push(r.norm());
+			break;
+		}
+
+		// $t = AM^{-1}s$, $ω = \frac{t^T s}{t^T t}$
+		let s_hat = preconditioner.apply(&s);
+		let t = a.matvec(&s_hat);
+		let tt = t.dot(&t);
+
+		if tt == E::zero() {
+			break;
+		}
+
+		omega = t.dot(&s) / tt;
+
+		// $x_k = x_{k-1} + αM^{-1}p_k + ωM^{-1}s$, $r_k = s - ωt$
+		x.axpy(alpha, &p_hat);
+		x.axpy(omega, &s_hat);
+		r = s;
+		r.axpy(-omega, &t);
+		residuals.push(r.norm());
+
+		if omega == E::zero() {
+			break;
+		}
+	}
+
+	Convergence {
+		converged: residuals[residuals.len() - 1] <= tolerance,
+		x,
+		residuals,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		matrix::BandMatrix,
+		preconditioner::Identity,
+		test_matrices::{num3_matrix, num5_matrix},
+	};
+
+	#[test]
+	fn conjugate_gradient() {
+		let a = num5_matrix(124);
+		let b = Vector::from_iter((1..=124).map(|i| i as f64));
+		let exact = a.clone().lu_decompose().solve(&b);
+
+		let res = a.conjugate_gradient(
+			Vector::new(124),
+			&b,
+			Criteria {
+				epsilon: 1e-12,
+				max_iters: 124,
+			},
+		);
+
+		assert!(res.converged);
+		assert!(res.iterations() < 40);
+		assert_eq!(res.residuals.len(), res.iterations() + 1);
+		assert!((&res.x - &exact).norm() < 1e-9);
+	}
+
+	#[test]
+	fn conjugate_gradient_exact_in_n_steps() {
+		let a = num5_matrix(8);
+		let b = Vector::from_iter((1..=8).map(|i| i as f64));
+		let exact = a.clone().lu_decompose().solve(&b);
+
+		let x = a
+			.conjugate_gradient_iter(Vector::new(8), &b, &Identity)
+			.take(8)
+			.last()
+			.unwrap();
+
+		assert!((&x - &exact).norm() < 1e-10);
+	}
+
+	fn nonsymmetric(n: usize) -> BandMatrix<f64, 1, 1> {
+		let mut a = BandMatrix::new(n);
+
+		for i in 1..=n {
+			a[(i, i)] = 2.0;
+		}
+
+		for i in 1..n {
+			a[(i + 1, i)] = -1.5;
+			a[(i, i + 1)] = 1.2;
+		}
+
+		a
+	}
+
+	#[test]
+	fn gmres_and_bicgstab_match_lu() {
+		let criteria = Criteria {
+			epsilon: 1e-12,
+			max_iters: 1000,
+		};
+
+		let a = num3_matrix(124);
+		let b = Vector::from_iter((1..=124).map(|i| i as f64));
+		let exact = a.clone().lu_decompose().solve(&b);
+
+		let gmres = a.gmres(Vector::new(124), &b, 10, criteria);
+		assert!(gmres.converged);
+		assert!((&gmres.x - &exact).norm() < 1e-9);
+
+		let bicgstab = a.bicgstab(Vector::new(124), &b, criteria);
+		assert!(bicgstab.converged);
+		assert!((&bicgstab.x - &exact).norm() < 1e-9);
+	}
+
+	#[test]
+	fn gmres_breakdown() {
+		let criteria = Criteria {
+			epsilon: 1e-12,
+			max_iters: 100,
+		};
+		let b = Vector::from_iter((1..=10).map(|i| i as f64));
+
+		// $h_{2,1} = 0$, so the first Krylov subspace already holds the solution
+		let a = BandMatrix::<f64, 1, 1>::from_constant_diagonals(10, &[0.0, 2.0, 0.0]).unwrap();
+		let res = a.gmres(Vector::new(10), &b, 5, criteria);
+		assert!(res.converged);
+		assert_eq!(res.iterations(), 1);
+		assert!((&res.x - &(&b / 2.0)).norm() < 1e-12);
+
+		// Nothing can be done with the zero matrix, but nothing may become NaN
+		let a = BandMatrix::<f64, 1, 1>::new(10);
+		let res = a.gmres(Vector::new(10), &b, 5, criteria);
+		assert!(!res.converged);
+		assert!(res.x == Vector::new(10));
+		assert!(res.residuals.iter().all(|&r| r == b.norm()));
+	}
+
+	#[test]
+	fn krylov_converges_where_jacobi_diverges() {
+		let criteria = Criteria {
+			epsilon: 1e-10,
+			max_iters: 2000,
+		};
+
+		let a = nonsymmetric(200);
+		let b = Vector::from_iter((1..=200).map(|i| (i as f64).sin()));
+		let exact = a.clone().lu_decompose().solve(&b);
+
+		assert!(a.jacobi(Vector::new(200), &b, 1e-10, 200).is_none());
+
+		let gmres = a.gmres(Vector::new(200), &b, 20, criteria);
+		assert!(gmres.converged);
+		assert!((&gmres.x - &exact).norm() < 1e-6);
+
+		let bicgstab = a.bicgstab(Vector::new(200), &b, criteria);
+		assert!(bicgstab.converged);
+		assert!((&bicgstab.x - &exact).norm() < 1e-6);
+	}
+}
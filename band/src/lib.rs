@@ -0,0 +1,59 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+mod acceleration;
+mod block;
+mod construct;
+mod convergence;
+#[cfg(feature = "nalgebra")]
+mod convert;
+mod cyclic;
+mod dense;
+#[cfg(feature = "std")]
+mod disk;
+mod fixed;
+mod krylov;
+mod matrix;
+mod multigrid;
+mod operator;
+mod parallel;
+#[cfg(feature = "plotters")]
+pub mod plot;
+mod preconditioner;
+mod rank_one;
+mod sherman_morrison;
+mod simd;
+mod spike;
+mod splitting;
+#[cfg(test)]
+mod test_matrices;
+mod toeplitz;
+mod vector;
+mod woodbury;
+
+pub use acceleration::{spectral_radius, Anderson, Chebyshev};
+pub use block::{BlockBandMatrix, BlockLuMatrices};
+pub use construct::BandMatrixError;
+pub use convergence::{Convergence, Criteria};
+pub use cyclic::CyclicTridiagonal;
+#[cfg(feature = "std")]
+pub use disk::{DiskBandMatrix, DiskElement, DiskLuMatrices, DiskVector};
+pub use fixed::{FixedBandMatrix, FixedLuMatrices};
+pub use krylov::ConjugateGradient;
+pub use matrix::{BandMatrix, BandMatrixView, BandMatrixViewMut, LuMatrices};
+pub use multigrid::{Cycle, Multigrid};
+pub use operator::LinearOperator;
+pub use parallel::MaybeSync;
+pub use preconditioner::{Diagonal, Identity, IncompleteCholesky, Preconditioner};
+pub use rank_one::BandPlusRankOne;
+pub use sherman_morrison::{solve_robust, ShermanMorrison, ShermanMorrisonError};
+pub use simd::MaybeSimd;
+pub use spike::Spike;
+pub use splitting::{
+	BackwardGaussSeidel, DampedJacobi, GaussSeidel, Jacobi, MulticolorGaussSeidel, Sor, Splitting,
+	Ssor,
+};
+pub use toeplitz::{ToeplitzBand, ToeplitzLu};
+pub use vector::{Vector, VectorView, VectorViewMut};
+pub use woodbury::solve_low_rank;
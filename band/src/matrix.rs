@@ -0,0 +1,1323 @@
+use alloc::{boxed::Box, format, vec::Vec};
+#[cfg(feature = "std")]
+use core::fmt::Display;
+use core::{
+	any,
+	fmt::{Debug, Formatter, Result as FmtResult},
+	iter,
+	ops::{Add, Deref, DerefMut, Index, IndexMut, Mul, RangeInclusive},
+};
+
+use num_traits::{real::Real, Num};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{
+	parallel::MaybeSync,
+	simd::{dot, sub_scaled, MaybeSimd, MIN_SIMD_BANDWIDTH},
+	vector::Vector,
+};
+
+/// The number of elements in the bands above which
+/// [`BandMatrix::lu_decompose`] uses the blocked algorithm
+pub(crate) const MIN_BLOCKED_BANDWIDTH: usize = 64;
+
+/// The number of columns in every panel of the blocked LU decomposition
+const LU_BLOCK_SIZE: usize = 32;
+
+/// A square [Band Matrix](https://en.wikipedia.org/wiki/Band_matrix) with L
+/// elements below/left of the main diagonal and U elements above/right of the
+/// main diagonal. Requires only `O(n)` storage space. Indexing into the matrix
+/// works as usual, but with the restriction that all elements outside of the
+/// bands are all 0 and read-only (mutable indexing on them will panic). The
+/// matrix owns its elements by default, but can also borrow them from any
+/// slice (see [`BandMatrixView`] and [`BandMatrixViewMut`]).
+#[derive(Clone)]
+pub struct BandMatrix<E, const L: usize, const U: usize, S = Box<[E]>> {
+	n: usize,
+	zero: E,
+	elements: S,
+}
+
+/// A square Band Matrix borrowing its elements from a slice
+pub type BandMatrixView<'a, E, const L: usize, const U: usize> = BandMatrix<E, L, U, &'a [E]>;
+
+/// A square Band Matrix mutably borrowing its elements from a slice
+pub type BandMatrixViewMut<'a, E, const L: usize, const U: usize> =
+	BandMatrix<E, L, U, &'a mut [E]>;
+
+/// The LU factors of a square Band Matrix with L elements below/left of the
+/// main diagonal and U elements above/right of the main diagonal.
+#[derive(Clone)]
+pub struct LuMatrices<E, const L: usize, const U: usize, S = Box<[E]>>(BandMatrix<E, L, U, S>, E);
+
+impl<E, const L: usize, const U: usize, S> BandMatrix<E, L, U, S> {
+	pub fn n(&self) -> usize {
+		self.n
+	}
+
+	fn idx(&self, index: (usize, usize)) -> Option<usize> {
+		assert_ne!(
+			index.0, 0,
+			"Index {index:?} out of bounds: matrices use 1-based indexing"
+		);
+		assert_ne!(
+			index.1, 0,
+			"Index {index:?} out of bounds: matrices use 1-based indexing"
+		);
+
+		assert!(
+			index.0 <= self.n,
+			"Index {index:?} out of bounds: column index is {} but matrix is {}x{}",
+			index.0,
+			self.n,
+			self.n,
+		);
+		assert!(
+			index.1 <= self.n,
+			"Index {index:?} out of bounds: row index is {} but matrix is {}x{}",
+			index.1,
+			self.n,
+			self.n
+		);
+
+		self.offset(index.0 - 1, index.1 - 1)
+	}
+
+	fn idx0(&self, index: (usize, usize)) -> Option<usize> {
+		assert!(
+			index.0 < self.n && index.1 < self.n,
+			"Index {index:?} out of bounds: matrix is {}x{} and at0 uses 0-based indexing",
+			self.n,
+			self.n,
+		);
+
+		self.offset(index.0, index.1)
+	}
+
+	/// Get the position of the 0-based (i, j) in the elements, if it lies in
+	/// the bands
+	fn offset(&self, i: usize, j: usize) -> Option<usize> {
+		if (i < j && j - i > U) || (i > j && i - j > L) {
+			return None;
+		}
+
+		let idx = if i < j {
+			(L + U + 1) * i + L + j.abs_diff(i)
+		} else {
+			(L + U + 1) * i + L - j.abs_diff(i)
+		};
+
+		Some(idx)
+	}
+}
+
+impl<E, const L: usize, const U: usize, S: Deref<Target = [E]>> BandMatrix<E, L, U, S> {
+	/// Get the element at the 0-based `index`, like `self[(i + 1, j + 1)]`
+	pub fn at0(&self, index: (usize, usize)) -> &E {
+		if let Some(idx) = self.idx0(index) {
+			&self.elements[idx]
+		} else {
+			&self.zero
+		}
+	}
+
+	/// Get the element at the 1-based `index`, the same as `self[index]`
+	pub fn at1(&self, index: (usize, usize)) -> &E {
+		&self[index]
+	}
+}
+
+impl<E, const L: usize, const U: usize, S: DerefMut<Target = [E]>> BandMatrix<E, L, U, S> {
+	/// Mutably get the element at the 0-based `index`, like
+	/// `&mut self[(i + 1, j + 1)]`. Panics outside of the bands.
+	pub fn at0_mut(&mut self, index: (usize, usize)) -> &mut E {
+		if let Some(idx) = self.idx0(index) {
+			&mut self.elements[idx]
+		} else {
+			panic!("Index {index:?} out of bounds for BandMatrix<_, {L}, {U}>({}): mutable indexing can not access zero elements", self.n)
+		}
+	}
+
+	/// Mutably get the element at the 1-based `index`, the same as
+	/// `&mut self[index]`. Panics outside of the bands.
+	pub fn at1_mut(&mut self, index: (usize, usize)) -> &mut E {
+		&mut self[index]
+	}
+}
+
+impl<E: Num, const L: usize, const U: usize> BandMatrix<E, L, U> {
+	/// Create a new zeroed Band Matrix of size n by n
+	pub fn new(n: usize) -> Self {
+		Self {
+			n,
+			zero: E::zero(),
+			elements: iter::repeat_with(E::zero).take((U + L + 1) * n).collect(),
+		}
+	}
+}
+
+impl<E: Num, const L: usize, const U: usize, S: Deref<Target = [E]>> BandMatrix<E, L, U, S> {
+	/// Create an n by n Band Matrix over the given elements, which are stored
+	/// row by row, with the `L + U + 1` elements of every row ordered from
+	/// left to right. The elements of the first `L` and last `U` rows which
+	/// lie outside of the matrix are ignored.
+	pub fn from_elements(n: usize, elements: S) -> Self {
+		assert_eq!(
+			elements.len(),
+			(L + U + 1) * n,
+			"BandMatrix<_, {L}, {U}>({n}) needs {} elements",
+			(L + U + 1) * n
+		);
+
+		Self {
+			n,
+			zero: E::zero(),
+			elements,
+		}
+	}
+
+	/// Borrow this matrix's elements
+	pub fn view(&self) -> BandMatrixView<'_, E, L, U> {
+		BandMatrix::from_elements(self.n, &self.elements)
+	}
+
+	/// Borrow the principal submatrix of this matrix consisting of rows and
+	/// columns `i..=j`
+	pub fn submatrix(&self, i: usize, j: usize) -> BandMatrixView<'_, E, L, U> {
+		let range = self.submatrix_range(i, j);
+		BandMatrix::from_elements(j - i + 1, &self.elements[range])
+	}
+
+	fn submatrix_range(&self, i: usize, j: usize) -> core::ops::Range<usize> {
+		assert!(
+			1 <= i && i <= j && j <= self.n,
+			"Submatrix {i}..={j} out of bounds: matrix is {}x{}",
+			self.n,
+			self.n
+		);
+
+		(L + U + 1) * (i - 1)..(L + U + 1) * j
+	}
+}
+
+impl<E: Num, const L: usize, const U: usize, S: DerefMut<Target = [E]>> BandMatrix<E, L, U, S> {
+	/// Mutably borrow this matrix's elements
+	pub fn view_mut(&mut self) -> BandMatrixViewMut<'_, E, L, U> {
+		BandMatrix::from_elements(self.n, &mut self.elements)
+	}
+
+	/// Mutably borrow the principal submatrix of this matrix consisting of
+	/// rows and columns `i..=j`. Only the elements inside of the submatrix
+	/// can be modified through it.
+	pub fn submatrix_mut(&mut self, i: usize, j: usize) -> BandMatrixViewMut<'_, E, L, U> {
+		let range = self.submatrix_range(i, j);
+		BandMatrix::from_elements(j - i + 1, &mut self.elements[range])
+	}
+}
+
+impl<E: Real + Copy, const L: usize, const U: usize, S: Deref<Target = [E]>>
+	BandMatrix<E, L, U, S>
+{
+	/// Perform the Gauss-Seidel algorithm, returning the result if it converged
+	pub fn gauss_seidel(
+		&self,
+		mut x: Vector<E>,
+		b: &Vector<E>,
+		epsilon: E,
+		max_iters: usize,
+	) -> Option<Vector<E>>
+	where
+		E: MaybeSimd,
+	{
+		assert_eq!(self.n(), x.n());
+		assert_eq!(x.n(), b.n());
+
+		let mut next;
+
+		for _ in 0..max_iters {
+			next = self.gauss_seidel_iteration(&x, b);
+
+			if (&x - &next).norm() < epsilon {
+				return Some(next);
+			}
+
+			x = next;
+		}
+
+		None
+	}
+
+	/// Perform the Jacobi algorithm, returning the result if it converged
+	pub fn jacobi(
+		&self,
+		mut x: Vector<E>,
+		b: &Vector<E>,
+		epsilon: E,
+		max_iters: usize,
+	) -> Option<Vector<E>>
+	where
+		E: MaybeSync + MaybeSimd,
+	{
+		assert_eq!(self.n(), x.n());
+		assert_eq!(x.n(), b.n());
+
+		let mut next;
+
+		for _ in 0..max_iters {
+			next = self.jacobi_iteration(&x, b);
+
+			if (&x - &next).norm() < epsilon {
+				return Some(next);
+			}
+
+			x = next;
+		}
+
+		None
+	}
+}
+
+impl<E: Num + Copy, const L: usize, const U: usize, S: Deref<Target = [E]>> BandMatrix<E, L, U, S> {
+	/// Copy this matrix into an owned band matrix
+	pub fn to_band(&self) -> BandMatrix<E, L, U> {
+		self.truncate()
+	}
+
+	/// Get the transpose of this matrix, which has U elements below/left of
+	/// the main diagonal and L elements above/right of the main diagonal
+	pub fn transpose(&self) -> BandMatrix<E, U, L> {
+		let mut res = BandMatrix::new(self.n);
+
+		for i in 1..=self.n {
+			for j in 1.max(i.saturating_sub(L))..=(i + U).min(self.n) {
+				res[(j, i)] = self[(i, j)];
+			}
+		}
+
+		res
+	}
+
+	/// Copy this matrix into a band matrix with `L2` elements below/left of
+	/// the main diagonal and `U2` elements above/right of the main diagonal,
+	/// dropping the elements outside of the new bands
+	pub fn truncate<const L2: usize, const U2: usize>(&self) -> BandMatrix<E, L2, U2> {
+		let mut res = BandMatrix::new(self.n);
+
+		for i in 1..=self.n {
+			for j in 1.max(i.saturating_sub(L.min(L2)))..=(i + U.min(U2)).min(self.n) {
+				res[(i, j)] = self[(i, j)];
+			}
+		}
+
+		res
+	}
+
+	/// Get the elements $a_{i,lo}, ..., a_{i,hi}$ of the `i`th row, which are
+	/// stored contiguously (`hi = lo - 1` gives an empty slice)
+	fn row(&self, i: usize, lo: usize, hi: usize) -> &[E] {
+		let start = (L + U + 1) * (i - 1) + L + lo - i;
+		&self.elements[start..start + (hi + 1 - lo)]
+	}
+
+	/// Perform one Gauss-Seidel iteration, returning the next `x`
+	pub fn gauss_seidel_iteration<X: Deref<Target = [E]>, B: Deref<Target = [E]>>(
+		&self,
+		x: &Vector<E, X>,
+		b: &Vector<E, B>,
+	) -> Vector<E>
+	where
+		E: MaybeSimd,
+	{
+		assert_eq!(self.n(), x.n());
+		assert_eq!(x.n(), b.n());
+
+		let mut next = Vector::new(x.n());
+
+		for i in 1..=x.n() {
+			// $x_i^{(k+1)} = \frac{1}{a_{i,i}} (b_i - \sum_{j = 1}^{i - 1} a_{i,j}x_j^{(k + 1)} - \sum_{j = i + 1}^n a_{i,j}x_j^{(k)})$
+			let (lo, hi) = (1.max(i.saturating_sub(L)), (i + U).min(x.n()));
+
+			next[i] = (b[i]
+				- dot(self.row(i, lo, i - 1), &next.as_slice()[lo - 1..i - 1])
+				- dot(self.row(i, i + 1, hi), &x.as_slice()[i..hi]))
+				/ (self[(i, i)]);
+		}
+
+		next
+	}
+
+	/// Perform one Jacobi iteration, returning the next `x`. The rows are
+	/// independent, so they are updated in parallel if the `rayon` feature is
+	/// enabled.
+	pub fn jacobi_iteration<X: Deref<Target = [E]>, B: Deref<Target = [E]>>(
+		&self,
+		x: &Vector<E, X>,
+		b: &Vector<E, B>,
+	) -> Vector<E>
+	where
+		E: MaybeSync + MaybeSimd,
+	{
+		assert_eq!(self.n(), x.n());
+		assert_eq!(x.n(), b.n());
+
+		let n = x.n();
+
+		// Share only the elements (and not the storage) between threads
+		let (a, x, b) = (self.view(), x.view(), b.view());
+
+		#[cfg(feature = "rayon")]
+		let rows = (1..=n).into_par_iter();
+		#[cfg(not(feature = "rayon"))]
+		let rows = 1..=n;
+
+		let values: Vec<E> = rows
+			.map(|i| {
+				// $x_i^{(k+1)} = \frac{1}{a_{i,i}} (b_i - \sum_{j \ne i} a_{i,j}x_j^{(k)})$
+				let (lo, hi) = (1.max(i.saturating_sub(L)), (i + U).min(n));
+
+				(b[i]
+					- dot(a.row(i, lo, i - 1), &x.as_slice()[lo - 1..i - 1])
+					- dot(a.row(i, i + 1, hi), &x.as_slice()[i..hi]))
+					/ (a[(i, i)])
+			})
+			.collect();
+
+		Vector::from_iter(values)
+	}
+
+	/// Perform one multicolour Gauss-Seidel iteration, returning the next `x`.
+	/// The rows are split into `max(L, U) + 1` colours, such that no row
+	/// depends on another row of the same colour, and all rows of one colour
+	/// are updated at once (in parallel if the `rayon` feature is enabled).
+	/// This is the Gauss-Seidel method with the rows reordered by colour.
+	pub fn multicolor_gauss_seidel_iteration<X: Deref<Target = [E]>, B: Deref<Target = [E]>>(
+		&self,
+		x: &Vector<E, X>,
+		b: &Vector<E, B>,
+	) -> Vector<E>
+	where
+		E: MaybeSync + MaybeSimd,
+	{
+		assert_eq!(self.n(), x.n());
+		assert_eq!(x.n(), b.n());
+
+		let n = x.n();
+		let colors = L.max(U) + 1;
+		let mut next = Vector::from_iter(x.iter().copied());
+
+		// Share only the elements (and not the storage) between threads
+		let (a, b) = (self.view(), b.view());
+
+		for color in 1..=colors.min(n) {
+			// The rows of this colour are $i = c, c + (B + 1), c + 2(B + 1), ...$
+			let count = (n - color) / colors + 1;
+
+			#[cfg(feature = "rayon")]
+			let rows = (0..count).into_par_iter();
+			#[cfg(not(feature = "rayon"))]
+			let rows = 0..count;
+
+			let values: Vec<E> = rows
+				.map(|k| {
+					let i = color + k * colors;
+
+					// $x_i = \frac{1}{a_{i,i}} (b_i - \sum_{j \ne i} a_{i,j}x_j)$ with the
+					// latest $x_j$, which are all of other colours
+					let (lo, hi) = (1.max(i.saturating_sub(L)), (i + U).min(n));
+
+					(b[i]
+						- dot(a.row(i, lo, i - 1), &next.as_slice()[lo - 1..i - 1])
+						- dot(a.row(i, i + 1, hi), &next.as_slice()[i..hi]))
+						/ (a[(i, i)])
+				})
+				.collect();
+
+			for (k, value) in values.into_iter().enumerate() {
+				next[color + k * colors] = value;
+			}
+		}
+
+		next
+	}
+}
+
+impl<E: Num + Copy, const L: usize, const U: usize, S: DerefMut<Target = [E]>>
+	BandMatrix<E, L, U, S>
+{
+	/// Perform in-place LU decomposition of this matrix. This method assumes
+	/// that the zero element behaves like the number 0, i.e. `x + 0 = x` and
+	/// `x * 0 = 0`, and that the matrix admits LU decomposition. After this
+	/// method completes, this matrix will contain the L and U matrices, with
+	/// the L matrix's diagonal elements implied to be 1 and not stored.
+	///
+	/// Matrices with more than [`MIN_BLOCKED_BANDWIDTH`] elements in the bands
+	/// are decomposed by a blocked algorithm instead, and with the `simd`
+	/// feature enabled, narrower matrices with at least [`MIN_SIMD_BANDWIDTH`]
+	/// elements above the main diagonal are decomposed row by row. Both give
+	/// the same result up to rounding.
+	pub fn lu_decompose(mut self) -> LuMatrices<E, L, U, S>
+	where
+		E: MaybeSimd,
+	{
+		if self.n <= 1 {
+			return LuMatrices(self, E::one());
+		}
+
+		if L + U > MIN_BLOCKED_BANDWIDTH {
+			self.lu_decompose_blocked();
+		} else if cfg!(feature = "simd") && U >= MIN_SIMD_BANDWIDTH {
+			self.lu_decompose_rows();
+		} else {
+			self.lu_decompose_scalar();
+		}
+
+		LuMatrices(self, E::one())
+	}
+
+	/// Perform in-place LU decomposition of this matrix element by element
+	fn lu_decompose_scalar(&mut self) {
+		// Based on <http://mathonline.wikidot.com/the-algorithm-for-doolittle-s-method-for-lu-decompositions>,
+		// but optimized to be efficient for band matrices
+		for k in 1..=self.n {
+			// $u_{k,m} = a_{k,m} - \sum_{j=\max(1, k - L, m - U)}^{k-1} l_{k,j} u_{j,m}$ for $m = k, k + 1, ..., k + U \le n$
+			for m in k..=(k + U).min(self.n) {
+				self[(k, m)] = self[(k, m)]
+					- (1.max(k.saturating_sub(L)).max(m.saturating_sub(U))..=k - 1)
+						.map(|j| self[(k, j)] * self[(j, m)])
+						.reduce(Add::add)
+						.unwrap_or_else(E::zero);
+			}
+
+			// $l_{i,k} = \frac{(a_{i,k} - \sum_{j=\max(1, i - L, k - U)}^{k-1} l_{i,j} u_{j,k})}{u_{k,k}}$ for $i = k + 1, k + 2, ..., k + L \le n$
+			for i in k + 1..=(k + L).min(self.n) {
+				self[(i, k)] = (self[(i, k)]
+					- (1.max(i.saturating_sub(L)).max(k.saturating_sub(U))..=k - 1)
+						.map(|j| self[(i, j)] * self[(j, k)])
+						.reduce(Add::add)
+						.unwrap_or_else(E::zero))
+					/ self[(k, k)];
+			}
+		}
+	}
+
+	/// Perform in-place blocked right-looking LU decomposition of this matrix
+	/// (like LAPACK's `gbtrf`, but without pivoting). For every panel of
+	/// [`LU_BLOCK_SIZE`] columns, the panel is factorized and $U_{12}$ is
+	/// computed, and then the (at most L by U) trailing block is updated with
+	/// the dense matrix product $A_{22} = A_{22} - L_{21} U_{12}$
+	fn lu_decompose_blocked(&mut self)
+	where
+		E: MaybeSimd,
+	{
+		let (n, width) = (self.n, L + U + 1);
+		let (mut l21, mut u12, mut a22) = (Vec::new(), Vec::new(), Vec::new());
+
+		for k0 in (1..=n).step_by(LU_BLOCK_SIZE) {
+			let k1 = (k0 + LU_BLOCK_SIZE - 1).min(n);
+
+			// $\begin{bmatrix} A_{11} \\ A_{21} \end{bmatrix} = \begin{bmatrix} L_{11} \\ L_{21} \end{bmatrix} U_{11}$
+			for k in k0..=k1 {
+				for i in k + 1..=(k + L).min(n) {
+					let l = self[(i, k)] / self[(k, k)];
+					self[(i, k)] = l;
+					self.sub_row(i, k, l, k + 1..=k1.min(k + U));
+				}
+			}
+
+			// $U_{12} = L_{11}^{-1} A_{12}$
+			for i in k0 + 1..=k1 {
+				for k in k0.max(i.saturating_sub(L))..i {
+					let l = self[(i, k)];
+					self.sub_row(i, k, l, k1 + 1..=(k + U).min(n));
+				}
+			}
+
+			let (depth, rows, columns) = (k1 + 1 - k0, (k1 + L).min(n) - k1, (k1 + U).min(n) - k1);
+
+			if rows == 0 || columns == 0 {
+				continue;
+			}
+
+			// The trailing block lies inside of the bands, so its rows are
+			// stored contiguously, starting at $a_{i,k_1+1}$
+			let start = |i: usize| width * (i - 1) + L + k1 + 1 - i;
+
+			// Copy $L_{21}$, $U_{12}$ and $A_{22}$ into dense blocks, with zeros
+			// outside of the bands
+			l21.clear();
+			a22.clear();
+
+			for i in k1 + 1..=k1 + rows {
+				l21.extend((k0..=k1).map(|k| self[(i, k)]));
+				a22.extend_from_slice(&self.elements[start(i)..][..columns]);
+			}
+
+			u12.clear();
+
+			for k in k0..=k1 {
+				u12.extend((k1 + 1..=k1 + columns).map(|m| self[(k, m)]));
+			}
+
+			// $A_{22} = A_{22} - L_{21} U_{12}$
+			for (a_row, l_row) in a22.chunks_exact_mut(columns).zip(l21.chunks_exact(depth)) {
+				for (&l, u_row) in l_row.iter().zip(u12.chunks_exact(columns)) {
+					if !l.is_zero() {
+						sub_scaled(a_row, l, u_row);
+					}
+				}
+			}
+
+			for (i, a_row) in (k1 + 1..=k1 + rows).zip(a22.chunks_exact(columns)) {
+				self.elements[start(i)..][..columns].copy_from_slice(a_row);
+			}
+		}
+	}
+
+	/// Perform in-place LU decomposition of this matrix row by row, i.e. for
+	/// every row $i$ eliminate the elements $a_{i,k}$ for $k < i$
+	fn lu_decompose_rows(&mut self)
+	where
+		E: MaybeSimd,
+	{
+		for i in 1..=self.n {
+			self.eliminate(i, 1.max(i.saturating_sub(L))..=i - 1);
+		}
+	}
+
+	/// Eliminate the elements $a_{i,k}$ of the `i`th row for `k` in `columns`
+	/// by subtracting $l_{i,k}$ times the (already factorized) `k`th row of U,
+	/// with the subtractions done by the (possibly SIMD) [`sub_scaled`] kernel
+	fn eliminate(&mut self, i: usize, columns: RangeInclusive<usize>)
+	where
+		E: MaybeSimd,
+	{
+		for k in columns {
+			// $l_{i,k} = \frac{a_{i,k}}{u_{k,k}}$
+			let l = self[(i, k)] / self[(k, k)];
+			self[(i, k)] = l;
+
+			// $a_{i,m} = a_{i,m} - l_{i,k} u_{k,m}$ for $m = k + 1, ..., k + U \le n$
+			self.sub_row(i, k, l, k + 1..=(k + U).min(self.n));
+		}
+	}
+
+	/// Subtract `l` times the `k`th row from the `i`th row for $i > k$, in the
+	/// given `columns`, which must lie inside of the bands of both rows
+	fn sub_row(&mut self, i: usize, k: usize, l: E, columns: RangeInclusive<usize>)
+	where
+		E: MaybeSimd,
+	{
+		let width = L + U + 1;
+		let (start, end) = columns.into_inner();
+
+		if start > end {
+			return;
+		}
+
+		let (upper, lower) = self.elements.split_at_mut(width * (i - 1));
+		let k_row = &upper[width * (k - 1) + L + start - k..][..end + 1 - start];
+		let i_row = &mut lower[L + start - i..][..end + 1 - start];
+
+		sub_scaled(i_row, l, k_row);
+	}
+
+	/// Perform in-place LU decomposition of this matrix slowly (without the
+	/// band matrix optimization)
+	pub fn lu_decompose_slow(mut self) -> LuMatrices<E, L, U, S> {
+		if self.n <= 1 {
+			return LuMatrices(self, E::one());
+		}
+
+		// Based on <http://mathonline.wikidot.com/the-algorithm-for-doolittle-s-method-for-lu-decompositions>
+		for k in 1..=self.n {
+			// $u_{k, m} = a_{k, m} - \sum_{j=1}^{k-1} l_{k,j} u_{j,m}$ for $m = k, k + 1, ..., n$
+			for m in k..=(k + U).min(self.n) {
+				self[(k, m)] = self[(k, m)]
+					- (1..=k - 1)
+						.map(|j| self[(k, j)] * self[(j, m)])
+						.reduce(Add::add)
+						.unwrap_or_else(E::zero);
+			}
+
+			// $l_{i, k} = \frac{(a_{i, k} - \sum_{j=1}^{k-1} l_{i,j} u_{j, k})}{u_{kk}}$ for $i = k + 1, k + 2, ..., n$
+			for i in k + 1..=(k + L).min(self.n) {
+				self[(i, k)] = (self[(i, k)]
+					- (1..=k - 1)
+						.map(|j| self[(i, j)] * self[(j, k)])
+						.reduce(Add::add)
+						.unwrap_or_else(E::zero))
+					/ self[(k, k)];
+			}
+		}
+
+		LuMatrices(self, E::one())
+	}
+}
+
+impl<E: Num + Copy, S: Deref<Target = [E]>> BandMatrix<E, 1, 1, S> {
+	/// Solve $Ax = b$ for $x$ using the Thomas algorithm, without decomposing
+	/// this matrix. Like LU decomposition, this method assumes that no pivoting
+	/// is needed (e.g. that the matrix is diagonally dominant).
+	pub fn thomas_solve<T: Deref<Target = [E]>>(&self, b: &Vector<E, T>) -> Vector<E> {
+		let mut x = Vector::from_iter(b.iter().copied());
+		self.thomas_solve_in_place(&mut x);
+		x
+	}
+
+	/// Solve $Ax = b$ for $x$ using the Thomas algorithm, overwriting `b` with
+	/// `x`
+	pub fn thomas_solve_in_place<T: DerefMut<Target = [E]>>(&self, b: &mut Vector<E, T>) {
+		assert_eq!(
+			self.n,
+			b.n(),
+			"Can't solve system of equations for a matrix and vector with different heights"
+		);
+
+		// Every row is stored as $[a_i, b_i, c_i]$. The storage of $a_1$ and
+		// $c_n$ lies outside of the matrix (and holds the neighbouring elements
+		// for submatrices), so they are skipped.
+		let n = self.n;
+		let mut c_prime = Vec::with_capacity(n);
+		let (mut c_prev, mut d_prev) = (E::zero(), E::zero());
+
+		// $c'_i = \frac{c_i}{b_i - a_i c'_{i-1}}$, $d'_i = \frac{d_i - a_i d'_{i-1}}{b_i - a_i c'_{i-1}}$
+		for (i, (row, d)) in self.elements.chunks_exact(3).zip(b.iter_mut()).enumerate() {
+			let (denominator, numerator) = if i == 0 {
+				(row[1], *d)
+			} else {
+				(row[1] - row[0] * c_prev, *d - row[0] * d_prev)
+			};
+
+			c_prev = if i == n - 1 {
+				E::zero()
+			} else {
+				row[2] / denominator
+			};
+			d_prev = numerator / denominator;
+
+			c_prime.push(c_prev);
+			*d = d_prev;
+		}
+
+		// $x_i = d'_i - c'_i x_{i+1}$
+		let mut x_next = E::zero();
+
+		for (c, x) in c_prime.into_iter().zip(b.iter_mut()).rev() {
+			*x = *x - c * x_next;
+			x_next = *x;
+		}
+	}
+}
+
+impl<E, const L: usize, const U: usize, S: Deref<Target = [E]>> LuMatrices<E, L, U, S> {
+	/// Get the `(i, j)`th index of the lower matrix
+	pub fn l(&self, i: usize, j: usize) -> &E {
+		if i == j {
+			&self.1
+		} else if i < j {
+			&self.0.zero
+		} else {
+			&self[(i, j)]
+		}
+	}
+
+	/// Get the `(i, j)`th index of the upper matrix
+	pub fn u(&self, i: usize, j: usize) -> &E {
+		if i > j {
+			&self.0.zero
+		} else {
+			&self[(i, j)]
+		}
+	}
+
+	/// Get the element of the combined LU matrix at the 0-based `index`
+	pub fn at0(&self, index: (usize, usize)) -> &E {
+		self.0.at0(index)
+	}
+
+	/// Get the element of the combined LU matrix at the 1-based `index`, the
+	/// same as `self[index]`
+	pub fn at1(&self, index: (usize, usize)) -> &E {
+		&self[index]
+	}
+}
+
+impl<E: Num + Copy, const L: usize, const U: usize, S: Deref<Target = [E]>> LuMatrices<E, L, U, S> {
+	pub fn det(&self) -> E {
+		let mut res = E::one();
+
+		for i in 1..=self.0.n {
+			res = res * self[(i, i)];
+		}
+
+		res
+	}
+
+	pub fn solve<T: Deref<Target = [E]>>(&self, b: &Vector<E, T>) -> Vector<E> {
+		let mut x = Vector::from_iter(b.iter().copied());
+		self.solve_in_place(&mut x);
+		x
+	}
+
+	/// Solve $LUx = b$ for $x$, overwriting `b` with `x`
+	pub fn solve_in_place<T: DerefMut<Target = [E]>>(&self, b: &mut Vector<E, T>) {
+		assert_eq!(
+			self.0.n(),
+			b.n(),
+			"Can't solve system of equations for a matrix and vector with different heights"
+		);
+
+		// $Ly = b$
+		// $y_m = \frac{b_m - \sum_{i=1}^{m-1} l_{m,i} y_i}{l_{m,m}}$ for $m = 1, ..., n$
+		// $= b_m - \sum_{i=\max(1, m - L)}^{m-1} l_{m,i} y_i$ for $m = 1, ..., n$
+		for m in 1..=self.0.n() {
+			b[m] = b[m]
+				- (1.max(m.saturating_sub(L))..=m - 1)
+					.map(|i| *self.l(m, i) * b[i])
+					.reduce(Add::add)
+					.unwrap_or_else(E::zero);
+		}
+
+		// $Ux = y$
+		// $x_m = \frac{y_m - \sum_{i=m+1}^{n} u_{m,i} x_i}{u_{m,m}}$ for $m = n, ..., 1$
+		// $= \frac{y_m - \sum_{i=m+1}^{\min(n, m + U)} u_{m,i} x_i}{u_{m,m}}$ for $m = n, ..., 1$
+		for m in (1..=self.0.n()).rev() {
+			b[m] = (b[m]
+				- (m + 1..=self.0.n().min(m + U))
+					.map(|i| *self.u(m, i) * b[i])
+					.reduce(Add::add)
+					.unwrap_or_else(E::zero))
+				/ *self.u(m, m);
+		}
+	}
+
+	/// Solve $LUx = b$ for every `b` in `bs`, which are independent, so they
+	/// are solved in parallel if the `rayon` feature is enabled
+	pub fn solve_many(&self, bs: &[Vector<E>]) -> Vec<Vector<E>>
+	where
+		E: MaybeSync,
+		S: MaybeSync,
+	{
+		let mut xs = bs.to_vec();
+		self.solve_many_in_place(&mut xs);
+		xs
+	}
+
+	/// Solve $LUx = b$ for every `b` in `bs`, overwriting each `b` with its
+	/// `x` (in parallel if the `rayon` feature is enabled)
+	pub fn solve_many_in_place(&self, bs: &mut [Vector<E>])
+	where
+		E: MaybeSync,
+		S: MaybeSync,
+	{
+		#[cfg(feature = "rayon")]
+		let bs = bs.par_iter_mut();
+		#[cfg(not(feature = "rayon"))]
+		let bs = bs.iter_mut();
+
+		bs.for_each(|b| self.solve_in_place(b));
+	}
+}
+
+impl<E: PartialEq, const L: usize, const U: usize, S: Deref<Target = [E]>> PartialEq
+	for BandMatrix<E, L, U, S>
+{
+	fn eq(&self, other: &Self) -> bool {
+		// Only compare the elements inside of the matrix, since the ones
+		// outside of a submatrix can be non-zero
+		self.n == other.n
+			&& (1..=self.n).all(|i| {
+				(1.max(i.saturating_sub(L))..=(i + U).min(self.n))
+					.all(|j| self[(i, j)] == other[(i, j)])
+			})
+	}
+}
+
+impl<E: PartialEq, const L: usize, const U: usize, S: Deref<Target = [E]>> PartialEq
+	for LuMatrices<E, L, U, S>
+{
+	fn eq(&self, other: &Self) -> bool {
+		self.0 == other.0 && self.1 == other.1
+	}
+}
+
+impl<E: Debug, const L: usize, const U: usize, S: Deref<Target = [E]>> Debug
+	for BandMatrix<E, L, U, S>
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		if f.alternate() {
+			f.debug_struct(&format!(
+				"BandMatrix<{}, L = {L}, U = {U}>",
+				any::type_name::<E>()
+			))
+			.field("n", &self.n)
+			.field("zero", &self.zero)
+			.field("elements", &&*self.elements)
+			.finish()
+		} else {
+			let width = f.width().unwrap_or(1);
+			let precision = f.precision().unwrap_or(6);
+			let full_width =
+				(width * (L + U + 1) + L + U).max((2 + precision) * (L + U + 1) + L + U);
+
+			write!(f, "┌ ")?;
+			for _ in 0..full_width {
+				write!(f, " ")?;
+			}
+			writeln!(f, " ┐(L = {L}, U = {U})")?;
+
+			for i in 0..self.n {
+				write!(f, "┆ ")?;
+
+				for j in 0..(L + U + 1) {
+					write!(
+						f,
+						"{:width$.precision$?} ",
+						self.elements[(L + U + 1) * i + j],
+						width = width,
+						precision = precision
+					)?;
+				}
+
+				writeln!(f, "┆")?;
+			}
+
+			write!(f, "└ ")?;
+			for _ in 0..full_width {
+				write!(f, " ")?;
+			}
+			writeln!(f, " ┘{}×{}", self.n, self.n)?;
+
+			Ok(())
+		}
+	}
+}
+
+impl<E: Debug, const L: usize, const U: usize, S: Deref<Target = [E]>> Debug
+	for LuMatrices<E, L, U, S>
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		self.0.fmt(f)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<E: Display, const L: usize, const U: usize, S: Deref<Target = [E]>> Display
+	for BandMatrix<E, L, U, S>
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		let width = f.width().unwrap_or(1);
+		let precision = f.precision().unwrap_or(6);
+		let full_width = (width * self.n + self.n - 1).max((2 + precision) * self.n + self.n - 1);
+
+		write!(f, "┌ ")?;
+		for _ in 0..full_width {
+			write!(f, " ")?;
+		}
+		writeln!(f, " ┐")?;
+
+		for i in 1..=self.n {
+			write!(f, "│ ")?;
+
+			for j in 1..=self.n {
+				write!(
+					f,
+					"{:width$.precision$} ",
+					self[(i, j)],
+					width = width,
+					precision = precision
+				)?;
+			}
+
+			writeln!(f, "│")?;
+		}
+
+		write!(f, "└ ")?;
+		for _ in 0..full_width {
+			write!(f, " ")?;
+		}
+		writeln!(f, " ┘{}×{}", self.n, self.n)?;
+
+		Ok(())
+	}
+}
+
+#[cfg(feature = "std")]
+impl<E: Display, const L: usize, const U: usize, S: Deref<Target = [E]>> Display
+	for LuMatrices<E, L, U, S>
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		self.0.fmt(f)
+	}
+}
+
+impl<E, const L: usize, const U: usize, S: Deref<Target = [E]>> Index<(usize, usize)>
+	for BandMatrix<E, L, U, S>
+{
+	type Output = E;
+
+	fn index(&self, index: (usize, usize)) -> &Self::Output {
+		if let Some(idx) = self.idx(index) {
+			&self.elements[idx]
+		} else {
+			&self.zero
+		}
+	}
+}
+
+impl<E, const L: usize, const U: usize, S: DerefMut<Target = [E]>> IndexMut<(usize, usize)>
+	for BandMatrix<E, L, U, S>
+{
+	fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+		if let Some(idx) = self.idx(index) {
+			&mut self.elements[idx]
+		} else {
+			panic!("Index {index:?} out of bounds for BandMatrix<_, {L}, {U}>({}): mutable indexing can not access zero elements", self.n)
+		}
+	}
+}
+
+impl<
+		E: Num + Copy,
+		const L: usize,
+		const U: usize,
+		S: Deref<Target = [E]>,
+		T: Deref<Target = [E]>,
+	> Mul<&Vector<E, T>> for &BandMatrix<E, L, U, S>
+{
+	type Output = Vector<E>;
+
+	fn mul(self, rhs: &Vector<E, T>) -> Self::Output {
+		assert_eq!(
+			self.n(),
+			rhs.n(),
+			"Can't multiply a matrix and vector with different sizes"
+		);
+
+		// $(Ax)_i = \sum_{j=\max(1, i - L)}^{\min(n, i + U)} a_{i,j} x_j$
+		Vector::from_iter((1..=self.n).map(|i| {
+			(1.max(i.saturating_sub(L))..=(i + U).min(self.n))
+				.map(|j| self[(i, j)] * rhs[j])
+				.reduce(Add::add)
+				.unwrap_or_else(E::zero)
+		}))
+	}
+}
+
+impl<E, const L: usize, const U: usize, S: Deref<Target = [E]>> Index<(usize, usize)>
+	for LuMatrices<E, L, U, S>
+{
+	type Output = E;
+
+	fn index(&self, index: (usize, usize)) -> &Self::Output {
+		&self.0[index]
+	}
+}
+
+impl<E, const L: usize, const U: usize, S: DerefMut<Target = [E]>> IndexMut<(usize, usize)>
+	for LuMatrices<E, L, U, S>
+{
+	fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+		&mut self.0[index]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::vector::{VectorView, VectorViewMut};
+
+	#[test]
+	fn decompose_rows() {
+		let n = 100;
+		let mut a = BandMatrix::<f64, 10, 12>::new(n);
+
+		for i in 1..=n {
+			for j in 1.max(i.saturating_sub(10))..=(i + 12).min(n) {
+				a[(i, j)] = if i == j {
+					30.0
+				} else {
+					1.0 / (i + 2 * j) as f64
+				};
+			}
+		}
+
+		let mut rows = a.clone();
+		rows.lu_decompose_rows();
+		let slow = a.clone().lu_decompose_slow();
+		let lu = a.lu_decompose();
+
+		for i in 1..=n {
+			for j in 1.max(i.saturating_sub(10))..=(i + 12).min(n) {
+				assert!((rows[(i, j)] - slow[(i, j)]).abs() < 1e-12);
+				assert!((lu[(i, j)] - slow[(i, j)]).abs() < 1e-12);
+			}
+		}
+	}
+
+	/// Generate a diagonally dominant matrix with wide bands
+	fn wide<const L: usize, const U: usize>(n: usize) -> BandMatrix<f64, L, U> {
+		BandMatrix::from_fn(n, |i, j| {
+			if i == j {
+				(L + U + 10) as f64
+			} else {
+				1.0 / (i + 2 * j) as f64
+			}
+		})
+	}
+
+	/// Check that every algorithm gives the same result as the scalar one
+	fn check_wide<const L: usize, const U: usize>(n: usize) {
+		let a = wide::<L, U>(n);
+
+		let mut scalar = a.clone();
+		scalar.lu_decompose_scalar();
+		let mut blocked = a.clone();
+		blocked.lu_decompose_blocked();
+		let mut rows = a.clone();
+		rows.lu_decompose_rows();
+		let slow = a.clone().lu_decompose_slow();
+		let lu = a.lu_decompose();
+
+		for i in 1..=n {
+			for j in 1.max(i.saturating_sub(L))..=(i + U).min(n) {
+				let expected = scalar[(i, j)];
+				assert!((blocked[(i, j)] - expected).abs() < 1e-12);
+				assert!((rows[(i, j)] - expected).abs() < 1e-12);
+				assert!((slow[(i, j)] - expected).abs() < 1e-12);
+				assert!((lu[(i, j)] - expected).abs() < 1e-12);
+			}
+		}
+	}
+
+	#[test]
+	fn decompose_wide() {
+		// Panels which are narrower and wider than the bands, and matrices
+		// smaller than a panel
+		check_wide::<40, 50>(300);
+		check_wide::<70, 3>(250);
+		check_wide::<2, 90>(250);
+		check_wide::<100, 100>(257);
+		check_wide::<40, 50>(20);
+		check_wide::<40, 50>(2);
+	}
+
+	#[test]
+	fn decompose() {
+		let mut a = BandMatrix::<f64, 1, 1>::new(2);
+
+		a[(1, 1)] = 4.0;
+		a[(1, 2)] = 3.0;
+		a[(2, 1)] = 6.0;
+		a[(2, 2)] = 3.0;
+
+		let b = a.clone();
+
+		assert_eq!(a, b);
+		let a = a.lu_decompose();
+		let b = b.lu_decompose_slow();
+		assert_eq!(a, b);
+
+		assert_eq!(a[(1, 1)], 4.0);
+		assert_eq!(a[(1, 2)], 3.0);
+		assert_eq!(a[(2, 1)], 1.5);
+		assert_eq!(a[(2, 2)], -1.5);
+
+		assert_eq!(b[(1, 1)], 4.0);
+		assert_eq!(b[(1, 2)], 3.0);
+		assert_eq!(b[(2, 1)], 1.5);
+		assert_eq!(b[(2, 2)], -1.5);
+	}
+
+	#[test]
+	fn decompose_slow_equals_fast() {
+		for size in 1..100 {
+			let mut a = BandMatrix::<f64, 1, 1>::new(size);
+
+			for i in 1..=size - 1 {
+				a[(i + 1, i)] = i.pow(2) as f64;
+				a[(i, i + 1)] = 1.0 / i as f64;
+			}
+
+			for i in 1..=size {
+				a[(i, i)] = i as f64;
+			}
+
+			let b = a.clone();
+
+			assert_eq!(a, b);
+			let a = a.lu_decompose();
+			let b = b.lu_decompose_slow();
+			assert_eq!(a, b);
+		}
+
+		for size in 2..100 {
+			let mut a = BandMatrix::<f64, 2, 2>::new(size);
+
+			for i in 1..=size - 2 {
+				a[(i + 2, i)] = (i + 5).pow(3) as f64 + 5.0;
+				a[(i, i + 2)] = 0.2 / i.pow(4) as f64;
+			}
+
+			for i in 1..=size - 1 {
+				a[(i + 1, i)] = i.pow(2) as f64;
+				a[(i, i + 1)] = 1.0 / i as f64;
+			}
+
+			for i in 1..=size {
+				a[(i, i)] = i as f64;
+			}
+
+			let b = a.clone();
+
+			assert_eq!(a, b);
+			let a = a.lu_decompose();
+			let b = b.lu_decompose_slow();
+			assert_eq!(a, b);
+		}
+	}
+
+	#[test]
+	fn views() {
+		let a = crate::test_matrices::num5_matrix(50);
+		let b = crate::test_matrices::num5_b(50);
+		let x = a.clone().lu_decompose().solve(&b);
+
+		// A view over an external buffer solves the same system
+		let mut buffer: Vec<f64> = a.view().elements.to_vec();
+		let view = BandMatrixView::<f64, 2, 2>::from_elements(50, &buffer);
+		let elements: Vec<f64> = b.iter().copied().collect();
+		let b_view = VectorView::from_elements(&elements[..]);
+
+		assert!(view == a.view());
+		assert!((&(&view * &b_view) - &(&a * &b)).norm() == 0.0);
+		assert!((&view.to_band().lu_decompose().solve(&b_view) - &x).norm() == 0.0);
+		assert!((&view.jacobi_iteration(&x, &b_view) - &x).norm() < 1e-10);
+
+		// Decomposing a mutable view overwrites the buffer with the factors
+		let lu = BandMatrixViewMut::<f64, 2, 2>::from_elements(50, &mut buffer).lu_decompose();
+		assert!((&lu.solve(&b) - &x).norm() == 0.0);
+		assert!(buffer[..] != *a.view().elements);
+	}
+
+	#[test]
+	fn submatrix() {
+		let mut a = crate::test_matrices::num5_matrix(30);
+		let sub = a.submatrix(11, 20);
+		let mut expected = BandMatrix::<f64, 2, 2>::new(10);
+
+		for i in 1..=10usize {
+			for j in 1.max(i.saturating_sub(2))..=(i + 2).min(10) {
+				expected[(i, j)] = a[(i + 10, j + 10)];
+			}
+		}
+
+		assert_eq!(sub.n(), 10);
+		assert!(sub == expected.view());
+		assert!(sub.to_band() == expected);
+
+		let b = crate::test_matrices::num5_b(10);
+		let x = expected.clone().lu_decompose().solve(&b);
+		assert!((&sub.to_band().lu_decompose().solve(&b) - &x).norm() == 0.0);
+
+		// Decomposing a submatrix only changes the elements inside of it
+		let original = a.clone();
+		let expected = expected.lu_decompose();
+		let lu = a.submatrix_mut(11, 20).lu_decompose();
+		assert!((&lu.solve(&b) - &x).norm() == 0.0);
+
+		for i in 1..=30usize {
+			for j in 1.max(i.saturating_sub(2))..=(i + 2).min(30) {
+				if (11..=20).contains(&i) && (11..=20).contains(&j) {
+					assert_eq!(a[(i, j)], expected[(i - 10, j - 10)]);
+				} else {
+					assert_eq!(a[(i, j)], original[(i, j)]);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn solve_many() {
+		let lu = crate::test_matrices::num4_matrix(80).lu_decompose();
+		let bs: Vec<_> = (1..=10)
+			.map(|k| Vector::from_iter((1..=80).map(|i| ((i * k) % 7) as f64)))
+			.collect();
+
+		let xs = lu.solve_many(&bs);
+
+		assert_eq!(xs.len(), bs.len());
+
+		for (x, b) in xs.iter().zip(&bs) {
+			assert_eq!(*x, lu.solve(b));
+		}
+	}
+
+	#[test]
+	fn thomas() {
+		let n = 60;
+		let mut a = BandMatrix::<f64, 1, 1>::new(n);
+
+		for i in 1..=n {
+			a[(i, i)] = 4.0 + (i % 3) as f64;
+		}
+
+		for i in 1..n {
+			a[(i, i + 1)] = -1.0;
+			a[(i + 1, i)] = 1.0 / i as f64;
+		}
+
+		let b = Vector::from_iter((1..=n).map(|i| (i * i % 11) as f64));
+		let x = a.thomas_solve(&b);
+
+		// The elements just outside of a submatrix don't affect its solution
+		a[(21, 20)] = f64::INFINITY;
+		a[(40, 41)] = f64::INFINITY;
+		let sub = a.submatrix(21, 40);
+		let head = Vector::from_iter((1..=20).map(|i| b[i]));
+		let mut elements = head.as_slice().to_vec();
+		sub.thomas_solve_in_place(&mut VectorViewMut::from_elements(&mut elements[..]));
+		let z = sub.to_band().lu_decompose().solve(&head);
+
+		for i in 1..=20 {
+			assert!((elements[i - 1] - z[i]).abs() < 1e-14);
+		}
+
+		a[(21, 20)] = 1.0 / 20.0;
+		a[(40, 41)] = -1.0;
+		let y = a.lu_decompose().solve(&b);
+
+		for i in 1..=n {
+			assert!((x[i] - y[i]).abs() < 1e-14);
+		}
+	}
+
+	#[test]
+	fn zero_based_indexing() {
+		let mut a = crate::test_matrices::num5_matrix(20);
+
+		for i in 0..20 {
+			for j in 0..20 {
+				assert_eq!(a.at0((i, j)), &a[(i + 1, j + 1)]);
+				assert_eq!(a.at0((i, j)), a.at1((i + 1, j + 1)));
+			}
+		}
+
+		*a.at0_mut((3, 5)) = 7.0;
+		assert_eq!(a[(4, 6)], 7.0);
+		*a.at1_mut((4, 6)) = 8.0;
+		assert_eq!(*a.at0((3, 5)), 8.0);
+
+		let lu = a.lu_decompose();
+		assert_eq!(lu.at0((0, 0)), &lu[(1, 1)]);
+		assert_eq!(lu.at1((20, 19)), lu.at0((19, 18)));
+	}
+
+	#[test]
+	#[should_panic]
+	fn zero_based_indexing_out_of_bounds() {
+		crate::test_matrices::num5_matrix(20).at0((20, 20));
+	}
+}
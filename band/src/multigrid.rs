@@ -0,0 +1,285 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Deref;
+
+use num_traits::real::Real;
+
+use crate::{
+	convergence::{Convergence, Criteria},
+	matrix::{BandMatrix, LuMatrices},
+	operator::LinearOperator,
+	simd::MaybeSimd,
+	splitting::Splitting,
+	vector::Vector,
+};
+
+/// The size of the largest grid which is solved directly instead of being
+/// coarsened further
+const COARSEST: usize = 7;
+
+/// The recursion pattern of a multigrid cycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cycle {
+	/// Visit each coarser grid once per cycle
+	V,
+	/// Visit each coarser grid twice per cycle
+	W,
+}
+
+/// A geometric multigrid solver for 1D stencil band matrices, using linear
+/// interpolation, full-weighting restriction and Galerkin coarse operators
+/// $A_{2h} = R A_h P$. Fine grid point $2j$ coincides with coarse grid point
+/// $j$, so grids of size $2^k - 1$ work best.
+pub struct Multigrid<E, const L: usize, const U: usize, M> {
+	levels: Vec<BandMatrix<E, L, U>>,
+	coarse: LuMatrices<E, L, U>,
+	smoother: M,
+	cycle: Cycle,
+	/// The number of smoothing iterations before the coarse grid correction
+	pub pre_smoothing: usize,
+	/// The number of smoothing iterations after the coarse grid correction
+	pub post_smoothing: usize,
+}
+
+impl<E: Real + MaybeSimd, const L: usize, const U: usize, M: Splitting<E>> Multigrid<E, L, U, M> {
+	/// Build the grid hierarchy for a copy of `a`, which will be smoothed using
+	/// the method defined by `smoother` (two pre- and post-smoothing iterations
+	/// by default). Requires `L, U >= 1`, which guarantees that the coarse
+	/// operators fit in the same bands.
+	pub fn new<S: Deref<Target = [E]>>(
+		a: &BandMatrix<E, L, U, S>,
+		smoother: M,
+		cycle: Cycle,
+	) -> Self {
+		assert!(
+			L >= 1 && U >= 1,
+			"Multigrid requires at least one band on each side of the diagonal"
+		);
+
+		let mut levels = vec![a.to_band()];
+
+		while levels[levels.len() - 1].n() > COARSEST {
+			let coarse = galerkin(&levels[levels.len() - 1]);
+			levels.push(coarse);
+		}
+
+		let coarse = levels[levels.len() - 1].clone().lu_decompose();
+
+		Self {
+			levels,
+			coarse,
+			smoother,
+			cycle,
+			pre_smoothing: 2,
+			post_smoothing: 2,
+		}
+	}
+
+	/// Get the number of grids in the hierarchy
+	pub fn depth(&self) -> usize {
+		self.levels.len()
+	}
+
+	/// Perform one multigrid cycle, returning the next `x`
+	pub fn iteration(&self, x: &Vector<E>, b: &Vector<E>) -> Vector<E> {
+		assert_eq!(self.levels[0].n(), x.n());
+		assert_eq!(x.n(), b.n());
+
+		self.cycle(0, x.clone(), b)
+	}
+
+	/// Perform multigrid cycles until the relative residual falls below the
+	/// tolerance
+	pub fn solve(&self, mut x: Vector<E>, b: &Vector<E>, criteria: Criteria<E>) -> Convergence<E> {
+		let a = &self.levels[0];
+		let tolerance = criteria.epsilon * b.norm();
+		let mut residuals = vec![a.residual(&x, b).norm()];
+
+		while residuals[residuals.len() - 1] > tolerance && residuals.len() <= criteria.max_iters {
+			x = self.iteration(&x, b);
+			residuals.push(a.residual(&x, b).norm());
+		}
+
+		Convergence {
+			converged: residuals[residuals.len() - 1] <= tolerance,
+			x,
+			residuals,
+		}
+	}
+
+	fn cycle(&self, level: usize, mut x: Vector<E>, b: &Vector<E>) -> Vector<E> {
+		if level == self.levels.len() - 1 {
+			return self.coarse.solve(b);
+		}
+
+		let a = &self.levels[level];
+
+		for _ in 0..self.pre_smoothing {
+			x = a.splitting_iteration(&self.smoother, &x, b);
+		}
+
+		// $r_{2h} = R(b - A_h x)$
+		let residual = restrict(&a.residual(&x, b));
+
+		// $A_{2h} e_{2h} = r_{2h}$
+		let mut error = Vector::new(residual.n());
+		let visits = match self.cycle {
+			Cycle::V => 1,
+			Cycle::W => 2,
+		};
+
+		for _ in 0..visits {
+			error = self.cycle(level + 1, error, &residual);
+		}
+
+		// $x = x + Pe_{2h}$
+		x += prolong(&error, x.n());
+
+		for _ in 0..self.post_smoothing {
+			x = a.splitting_iteration(&self.smoother, &x, b);
+		}
+
+		x
+	}
+}
+
+/// Get the size of the coarse grid for a fine grid of size `n`
+fn coarse_size(n: usize) -> usize {
+	n / 2
+}
+
+/// Restrict `r` to the coarse grid using full weighting,
+/// $(Rr)_j = \frac{1}{4} r_{2j-1} + \frac{1}{2} r_{2j} + \frac{1}{4} r_{2j+1}$
+fn restrict<E: Real>(r: &Vector<E>) -> Vector<E> {
+	let n = r.n();
+	let two = E::one() + E::one();
+	let four = two + two;
+
+	Vector::from_iter((1..=coarse_size(n)).map(|j| {
+		let next = if 2 * j < n { r[2 * j + 1] } else { E::zero() };
+		(r[2 * j - 1] + two * r[2 * j] + next) / four
+	}))
+}
+
+/// Prolong `e` to the fine grid of size `n` using linear interpolation,
+/// $(Pe)_{2j} = e_j$, $(Pe)_{2j+1} = \frac{1}{2} (e_j + e_{j+1})$
+fn prolong<E: Real>(e: &Vector<E>, n: usize) -> Vector<E> {
+	let nc = e.n();
+	let two = E::one() + E::one();
+	let coarse = |j: usize| {
+		if j == 0 || j > nc {
+			E::zero()
+		} else {
+			e[j]
+		}
+	};
+
+	Vector::from_iter((1..=n).map(|i| {
+		if i % 2 == 0 {
+			coarse(i / 2)
+		} else {
+			(coarse(i / 2) + coarse(i / 2 + 1)) / two
+		}
+	}))
+}
+
+/// Compute the Galerkin coarse operator $A_{2h} = R A_h P$, where
+/// $R = \frac{1}{2} P^T$. Its bands are no wider than $A_h$'s for $L, U \ge 1$.
+fn galerkin<E: Real, const L: usize, const U: usize>(
+	a: &BandMatrix<E, L, U>,
+) -> BandMatrix<E, L, U> {
+	let n = a.n();
+	let nc = coarse_size(n);
+	let two = E::one() + E::one();
+	let half = E::one() / two;
+
+	// The non-zero elements of column $j$ of $P$ (and of row $j$ of $2R$)
+	let stencil = |j: usize| {
+		[(2 * j - 1, half), (2 * j, E::one()), (2 * j + 1, half)]
+			.into_iter()
+			.filter(move |&(i, _)| i <= n)
+	};
+
+	let mut res = BandMatrix::new(nc);
+
+	for j in 1..=nc {
+		for k in 1.max(j.saturating_sub(L))..=(j + U).min(nc) {
+			let mut sum = E::zero();
+
+			for (i, r) in stencil(j) {
+				for (l, p) in stencil(k) {
+					sum = sum + r * a[(i, l)] * p;
+				}
+			}
+
+			res[(j, k)] = sum * half;
+		}
+	}
+
+	res
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		splitting::{DampedJacobi, GaussSeidel},
+		test_matrices::poisson,
+	};
+
+	const CRITERIA: Criteria<f64> = Criteria {
+		epsilon: 1e-10,
+		max_iters: 100,
+	};
+
+	#[test]
+	fn galerkin_poisson() {
+		// The Galerkin operator of the Poisson matrix is the Poisson matrix on
+		// the coarse grid, scaled by $\frac{1}{4}$ (since $h$ is doubled)
+		let coarse = galerkin(&poisson(15));
+		let expected = poisson(7);
+
+		for i in 1..=7 {
+			for j in 1..=7 {
+				assert!((coarse[(i, j)] - expected[(i, j)] / 4.0).abs() < 1e-15);
+			}
+		}
+	}
+
+	#[test]
+	fn iterations_independent_of_size() {
+		let mut iterations = vec![];
+
+		for k in [6, 9, 12] {
+			let n = 2usize.pow(k) - 1;
+			let a = poisson(n);
+			let b = Vector::from_iter((1..=n).map(|i| ((i * i) % 7) as f64));
+			let exact = a.clone().lu_decompose().solve(&b);
+
+			for cycle in [Cycle::V, Cycle::W] {
+				let mg = Multigrid::new(&a, GaussSeidel, cycle);
+				let res = mg.solve(Vector::new(n), &b, CRITERIA);
+
+				assert!(res.converged);
+				assert!((&res.x - &exact).norm() / exact.norm() < 1e-8);
+				iterations.push(res.iterations());
+			}
+		}
+
+		assert!(iterations.iter().all(|&i| i <= 15));
+		assert!(iterations.iter().max().unwrap() - iterations.iter().min().unwrap() <= 4);
+	}
+
+	#[test]
+	fn num5_matrix() {
+		let n = 1000;
+		let a = crate::test_matrices::num5_matrix(n);
+		let b = crate::test_matrices::num5_b(n);
+		let exact = a.clone().lu_decompose().solve(&b);
+
+		let mg = Multigrid::new(&a, DampedJacobi(2.0 / 3.0), Cycle::V);
+		let res = mg.solve(Vector::new(n), &b, CRITERIA);
+
+		assert!(res.converged);
+		assert!((&res.x - &exact).norm() / exact.norm() < 1e-8);
+	}
+}
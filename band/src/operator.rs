@@ -0,0 +1,176 @@
+use core::ops::Deref;
+
+use num_traits::{real::Real, Num};
+
+use crate::{
+	convergence::{Convergence, Criteria},
+	cyclic::CyclicTridiagonal,
+	krylov::{self, ConjugateGradient},
+	matrix::BandMatrix,
+	preconditioner::{Identity, Preconditioner},
+	rank_one::BandPlusRankOne,
+	toeplitz::ToeplitzBand,
+	vector::Vector,
+};
+
+/// A square matrix which only has to be multiplied by vectors, which is all
+/// that the Krylov subspace methods need, so they work for every matrix
+/// structure in this crate
+pub trait LinearOperator<E> {
+	/// Get the size of the operator
+	fn n(&self) -> usize;
+
+	/// Compute $Ax$
+	fn matvec(&self, x: &Vector<E>) -> Vector<E>;
+
+	/// Compute the residual $b - Ax$
+	fn residual(&self, x: &Vector<E>, b: &Vector<E>) -> Vector<E>
+	where
+		E: Num + Copy,
+	{
+		b - self.matvec(x)
+	}
+
+	/// Start the Conjugate Gradient method from `x`, returning an iterator over
+	/// the consecutive approximations of the solution. The matrix must be
+	/// symmetric positive-definite.
+	fn conjugate_gradient_iter<'a, P: Preconditioner<E>>(
+		&'a self,
+		x: Vector<E>,
+		b: &Vector<E>,
+		preconditioner: &'a P,
+	) -> ConjugateGradient<'a, E, Self, P>
+	where
+		E: Real,
+	{
+		ConjugateGradient::new(self, x, b, preconditioner)
+	}
+
+	/// Perform the Conjugate Gradient method. The matrix must be symmetric
+	/// positive-definite.
+	fn conjugate_gradient(
+		&self,
+		x: Vector<E>,
+		b: &Vector<E>,
+		criteria: Criteria<E>,
+	) -> Convergence<E>
+	where
+		E: Real,
+	{
+		krylov::conjugate_gradient(self, x, b, &Identity, criteria)
+	}
+
+	/// Perform the preconditioned Conjugate Gradient method. Both the matrix
+	/// and the preconditioner must be symmetric positive-definite.
+	fn preconditioned_conjugate_gradient(
+		&self,
+		x: Vector<E>,
+		b: &Vector<E>,
+		preconditioner: &impl Preconditioner<E>,
+		criteria: Criteria<E>,
+	) -> Convergence<E>
+	where
+		E: Real,
+	{
+		krylov::conjugate_gradient(self, x, b, preconditioner, criteria)
+	}
+
+	/// Perform the restarted GMRES(m) method, restarting every `restart`
+	/// iterations. Works for any nonsingular matrix.
+	fn gmres(
+		&self,
+		x: Vector<E>,
+		b: &Vector<E>,
+		restart: usize,
+		criteria: Criteria<E>,
+	) -> Convergence<E>
+	where
+		E: Real,
+	{
+		krylov::gmres(self, x, b, restart, &Identity, criteria)
+	}
+
+	/// Perform the restarted GMRES(m) method with right preconditioning,
+	/// restarting every `restart` iterations. Works for any nonsingular matrix
+	/// and preconditioner.
+	fn preconditioned_gmres(
+		&self,
+		x: Vector<E>,
+		b: &Vector<E>,
+		restart: usize,
+		preconditioner: &impl Preconditioner<E>,
+		criteria: Criteria<E>,
+	) -> Convergence<E>
+	where
+		E: Real,
+	{
+		krylov::gmres(self, x, b, restart, preconditioner, criteria)
+	}
+
+	/// Perform the BiCGSTAB method. Works for any nonsingular matrix, but
+	/// may break down before converging.
+	fn bicgstab(&self, x: Vector<E>, b: &Vector<E>, criteria: Criteria<E>) -> Convergence<E>
+	where
+		E: Real,
+	{
+		krylov::bicgstab(self, x, b, &Identity, criteria)
+	}
+
+	/// Perform the BiCGSTAB method with right preconditioning. Works for any
+	/// nonsingular matrix and preconditioner, but may break down before
+	/// converging.
+	fn preconditioned_bicgstab(
+		&self,
+		x: Vector<E>,
+		b: &Vector<E>,
+		preconditioner: &impl Preconditioner<E>,
+		criteria: Criteria<E>,
+	) -> Convergence<E>
+	where
+		E: Real,
+	{
+		krylov::bicgstab(self, x, b, preconditioner, criteria)
+	}
+}
+
+impl<E: Num + Copy, const L: usize, const U: usize, S: Deref<Target = [E]>> LinearOperator<E>
+	for BandMatrix<E, L, U, S>
+{
+	fn n(&self) -> usize {
+		self.n()
+	}
+
+	fn matvec(&self, x: &Vector<E>) -> Vector<E> {
+		self * x
+	}
+}
+
+impl<E: Num + Copy, const L: usize, const U: usize> LinearOperator<E> for BandPlusRankOne<E, L, U> {
+	fn n(&self) -> usize {
+		self.n()
+	}
+
+	fn matvec(&self, x: &Vector<E>) -> Vector<E> {
+		self * x
+	}
+}
+
+impl<E: Num + Copy> LinearOperator<E> for CyclicTridiagonal<E> {
+	fn n(&self) -> usize {
+		self.n()
+	}
+
+	fn matvec(&self, x: &Vector<E>) -> Vector<E> {
+		self * x
+	}
+}
+
+impl<E: Num + Copy, const L: usize, const U: usize> LinearOperator<E> for ToeplitzBand<E, L, U> {
+	fn n(&self) -> usize {
+		self.n()
+	}
+
+	fn matvec(&self, x: &Vector<E>) -> Vector<E> {
+		self * x
+	}
+}
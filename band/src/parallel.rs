@@ -0,0 +1,18 @@
+/// A marker trait for types which can be shared between threads if the
+/// `rayon` feature is enabled. The parallel SPIKE, Jacobi, multicolor
+/// Gauss-Seidel and `solve_many` send the matrix elements to the rayon thread
+/// pool, so with the feature enabled they require `Send + Sync` scalars.
+#[cfg(feature = "rayon")]
+pub trait MaybeSync: Send + Sync {}
+
+#[cfg(feature = "rayon")]
+impl<T: Send + Sync> MaybeSync for T {}
+
+/// A marker trait for types which can be shared between threads if the
+/// `rayon` feature is enabled, which it isn't, so it is implemented for all
+/// types
+#[cfg(not(feature = "rayon"))]
+pub trait MaybeSync {}
+
+#[cfg(not(feature = "rayon"))]
+impl<T> MaybeSync for T {}
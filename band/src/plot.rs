@@ -0,0 +1,50 @@
+use plotters::{
+	coord::{types::RangedCoordi32, Shift},
+	prelude::*,
+};
+
+/// A chart of the errors $||x^{(k)} - x||$ of iterative methods, with the
+/// iteration `k` on the x axis and a logarithmic y axis
+pub type ErrorChart<'a, DB> = ChartContext<'a, DB, Cartesian2d<RangedCoordi32, LogCoord<f64>>>;
+
+/// Build an [`ErrorChart`] on `area` for up to `iterations` iterations and
+/// errors between `min_error` and `max_error`
+pub fn error_chart<'a, DB: DrawingBackend>(
+	area: &'a DrawingArea<DB, Shift>,
+	iterations: i32,
+	min_error: f64,
+	max_error: f64,
+) -> Result<ErrorChart<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>> {
+	let mut chart = ChartBuilder::on(area)
+		.set_label_area_size(LabelAreaPosition::Left, 60)
+		.set_label_area_size(LabelAreaPosition::Bottom, 60)
+		.build_cartesian_2d(0..iterations, (min_error..max_error).log_scale())?;
+
+	chart
+		.configure_mesh()
+		.x_desc("k")
+		.y_desc("||x⁽ᵏ⁾ - x||")
+		.draw()?;
+
+	Ok(chart)
+}
+
+/// Draw one line labelled `label` for the errors of every run of an iterative
+/// method, in the colour returned by `color` (called once per run)
+pub fn draw_errors<DB: DrawingBackend>(
+	chart: &mut ErrorChart<'_, DB>,
+	runs: &[Vec<f64>],
+	label: &str,
+	mut color: impl FnMut() -> HSLColor,
+) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+	for errors in runs {
+		let color = color();
+
+		chart
+			.draw_series(LineSeries::new((0..).zip(errors.iter().copied()), color))?
+			.label(label)
+			.legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+	}
+
+	Ok(())
+}
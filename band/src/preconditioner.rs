@@ -0,0 +1,213 @@
+use core::ops::{Add, Deref};
+
+use num_traits::{real::Real, Num};
+
+use crate::{
+	matrix::{BandMatrix, LuMatrices},
+	simd::MaybeSimd,
+	vector::Vector,
+};
+
+/// A preconditioner $M \approx A$ for an iterative method, which is applied by
+/// solving $Mz = r$ for $z$
+pub trait Preconditioner<E> {
+	/// Apply the preconditioner to `r`, returning $z = M^{-1}r$
+	fn apply(&self, r: &Vector<E>) -> Vector<E>;
+}
+
+/// The identity preconditioner $M = I$, i.e. no preconditioning
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Identity;
+
+/// The diagonal (Jacobi) preconditioner $M = D$
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagonal<E>(Vector<E>);
+
+/// The incomplete Cholesky factor $\tilde{L}$ of a symmetric positive-definite
+/// band matrix, with `B` elements below the main diagonal, used as the
+/// preconditioner $M = \tilde{L}\tilde{L}^T$
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncompleteCholesky<E, const B: usize>(BandMatrix<E, B, 0>);
+
+impl<E: Clone> Preconditioner<E> for Identity {
+	fn apply(&self, r: &Vector<E>) -> Vector<E> {
+		r.clone()
+	}
+}
+
+impl<E: Num + Copy> Diagonal<E> {
+	/// Create the diagonal preconditioner of `a`
+	pub fn new<const L: usize, const U: usize, S: Deref<Target = [E]>>(
+		a: &BandMatrix<E, L, U, S>,
+	) -> Self {
+		Self(Vector::from_iter((1..=a.n()).map(|i| E::one() / a[(i, i)])))
+	}
+}
+
+impl<E: Num + Copy> Preconditioner<E> for Diagonal<E> {
+	fn apply(&self, r: &Vector<E>) -> Vector<E> {
+		self.0.zip_map(r, |d, r| d * r)
+	}
+}
+
+impl<E: Num + Copy, const L: usize, const U: usize, S: Deref<Target = [E]>> Preconditioner<E>
+	for LuMatrices<E, L, U, S>
+{
+	fn apply(&self, r: &Vector<E>) -> Vector<E> {
+		self.solve(r)
+	}
+}
+
+impl<E: Real, const B: usize> Preconditioner<E> for IncompleteCholesky<E, B> {
+	fn apply(&self, r: &Vector<E>) -> Vector<E> {
+		let l = &self.0;
+		let n = l.n();
+		assert_eq!(n, r.n());
+
+		let mut y = Vector::new(n);
+		let mut z = Vector::new(n);
+
+		// $\tilde{L}y = r$
+		for m in 1..=n {
+			y[m] = (r[m]
+				- (1.max(m.saturating_sub(B))..=m - 1)
+					.map(|i| l[(m, i)] * y[i])
+					.reduce(Add::add)
+					.unwrap_or_else(E::zero))
+				/ l[(m, m)];
+		}
+
+		// $\tilde{L}^T z = y$
+		for m in (1..=n).rev() {
+			z[m] = (y[m]
+				- (m + 1..=n.min(m + B))
+					.map(|i| l[(i, m)] * z[i])
+					.reduce(Add::add)
+					.unwrap_or_else(E::zero))
+				/ l[(m, m)];
+		}
+
+		z
+	}
+}
+
+impl<E: Num + Copy, const L: usize, const U: usize, S: Deref<Target = [E]>> BandMatrix<E, L, U, S> {
+	/// Compute the incomplete LU factorization of this matrix which keeps
+	/// only the `L2` subdiagonals and `U2` superdiagonals closest to the main
+	/// diagonal. Because the LU factors of a band matrix don't have any
+	/// elements outside of its bands, this is the exact LU decomposition of
+	/// the matrix truncated to those bands, and `incomplete_lu::<L, U>()` is
+	/// the same as `lu_decompose()`.
+	pub fn incomplete_lu<const L2: usize, const U2: usize>(&self) -> LuMatrices<E, L2, U2>
+	where
+		E: MaybeSimd,
+	{
+		self.truncate::<L2, U2>().lu_decompose()
+	}
+}
+
+impl<E: Real, const L: usize, const U: usize, S: Deref<Target = [E]>> BandMatrix<E, L, U, S> {
+	/// Compute the incomplete Cholesky factorization of this symmetric matrix
+	/// which keeps only the `B` subdiagonals closest to the main diagonal,
+	/// using only the elements below the main diagonal. Returns `None` if the
+	/// factorization breaks down because of a non-positive pivot (which can
+	/// only happen if the truncated matrix isn't positive-definite).
+	pub fn incomplete_cholesky<const B: usize>(&self) -> Option<IncompleteCholesky<E, B>> {
+		let mut l = self.truncate::<B, 0>();
+		let n = l.n();
+
+		for k in 1..=n {
+			// $l_{k,k} = \sqrt{a_{k,k} - \sum_{j=\max(1, k - B)}^{k-1} l_{k,j}^2}$
+			let pivot = l[(k, k)]
+				- (1.max(k.saturating_sub(B))..=k - 1)
+					.map(|j| l[(k, j)] * l[(k, j)])
+					.reduce(Add::add)
+					.unwrap_or_else(E::zero);
+
+			if pivot <= E::zero() {
+				return None;
+			}
+
+			l[(k, k)] = pivot.sqrt();
+
+			// $l_{i,k} = \frac{a_{i,k} - \sum_{j=\max(1, i - B)}^{k-1} l_{i,j} l_{k,j}}{l_{k,k}}$ for $i = k + 1, ..., k + B \le n$
+			for i in k + 1..=(k + B).min(n) {
+				l[(i, k)] = (l[(i, k)]
+					- (1.max(i.saturating_sub(B))..=k - 1)
+						.map(|j| l[(i, j)] * l[(k, j)])
+						.reduce(Add::add)
+						.unwrap_or_else(E::zero))
+					/ l[(k, k)];
+			}
+		}
+
+		Some(IncompleteCholesky(l))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{convergence::Criteria, operator::LinearOperator, test_matrices::num5_matrix};
+
+	const CRITERIA: Criteria<f64> = Criteria {
+		epsilon: 1e-12,
+		max_iters: 1000,
+	};
+
+	#[test]
+	fn full_incomplete_factorizations_are_exact() {
+		let a = num5_matrix(50);
+		let b = Vector::from_iter((1..=50).map(|i| i as f64));
+		let exact = a.clone().lu_decompose().solve(&b);
+
+		assert!((&a.incomplete_lu::<2, 2>().apply(&b) - &exact).norm() < 1e-10);
+
+		let ic = a.incomplete_cholesky::<2>().unwrap();
+		assert!((&ic.apply(&b) - &exact).norm() < 1e-10);
+	}
+
+	#[test]
+	fn preconditioning_reduces_iterations() {
+		let a = num5_matrix(500);
+		let b = Vector::from_iter((1..=500).map(|i| i as f64));
+		let exact = a.clone().lu_decompose().solve(&b);
+
+		let cg = a.conjugate_gradient(Vector::new(500), &b, CRITERIA);
+		let ic = a.incomplete_cholesky::<1>().unwrap();
+		let pcg = a.preconditioned_conjugate_gradient(Vector::new(500), &b, &ic, CRITERIA);
+		let diagonal =
+			a.preconditioned_conjugate_gradient(Vector::new(500), &b, &Diagonal::new(&a), CRITERIA);
+
+		assert!(cg.converged && pcg.converged && diagonal.converged);
+		assert!(pcg.iterations() < cg.iterations());
+		assert!((&pcg.x - &exact).norm() < 1e-8);
+		assert!((&diagonal.x - &exact).norm() < 1e-8);
+
+		let ilu = a.incomplete_lu::<1, 1>();
+		let gmres = a.gmres(Vector::new(500), &b, 20, CRITERIA);
+		let pgmres = a.preconditioned_gmres(Vector::new(500), &b, 20, &ilu, CRITERIA);
+		let pbicgstab = a.preconditioned_bicgstab(Vector::new(500), &b, &ilu, CRITERIA);
+
+		assert!(gmres.converged && pgmres.converged && pbicgstab.converged);
+		assert!(pgmres.iterations() < gmres.iterations());
+		assert!((&pgmres.x - &exact).norm() < 1e-8);
+		assert!((&pbicgstab.x - &exact).norm() < 1e-8);
+	}
+
+	#[test]
+	fn incomplete_cholesky_breakdown() {
+		let mut a = BandMatrix::<f64, 1, 1>::new(3);
+
+		for i in 1..=3 {
+			a[(i, i)] = 1.0;
+		}
+
+		for i in 1..3 {
+			a[(i, i + 1)] = 2.0;
+			a[(i + 1, i)] = 2.0;
+		}
+
+		assert!(a.incomplete_cholesky::<1>().is_none());
+	}
+}
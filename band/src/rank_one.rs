@@ -0,0 +1,160 @@
+use core::ops::Mul;
+
+use num_traits::Num;
+
+use crate::{
+	matrix::BandMatrix, sherman_morrison::ShermanMorrison, simd::MaybeSimd, vector::Vector,
+};
+
+/// A square matrix $A = A' + uv^T$, where $A'$ is a band matrix with L
+/// elements below/left of the main diagonal and U elements above/right of the
+/// main diagonal. Requires only `O(n)` storage space, even though the matrix
+/// itself is (in general) dense.
+#[derive(Clone, PartialEq)]
+pub struct BandPlusRankOne<E, const L: usize, const U: usize> {
+	a_prime: BandMatrix<E, L, U>,
+	u: Vector<E>,
+	v: Vector<E>,
+}
+
+impl<E, const L: usize, const U: usize> BandPlusRankOne<E, L, U> {
+	/// Create the matrix $A' + uv^T$
+	pub fn new(a_prime: BandMatrix<E, L, U>, u: Vector<E>, v: Vector<E>) -> Self {
+		assert_eq!(a_prime.n(), u.n(), "A''s height must equal u's size");
+		assert_eq!(a_prime.n(), v.n(), "A''s height must equal v's size");
+
+		Self { a_prime, u, v }
+	}
+
+	pub fn n(&self) -> usize {
+		self.a_prime.n()
+	}
+
+	/// Get the band part $A'$ of this matrix
+	pub fn band(&self) -> &BandMatrix<E, L, U> {
+		&self.a_prime
+	}
+
+	/// Get the vector $u$ of this matrix
+	pub fn u(&self) -> &Vector<E> {
+		&self.u
+	}
+
+	/// Get the vector $v$ of this matrix
+	pub fn v(&self) -> &Vector<E> {
+		&self.v
+	}
+}
+
+impl<E: Num + Copy, const L: usize, const U: usize> BandPlusRankOne<E, L, U> {
+	/// Get the `(i, j)`th element of this matrix, $a'_{i,j} + u_i v_j$
+	pub fn get(&self, i: usize, j: usize) -> E {
+		self.a_prime[(i, j)] + self.u[i] * self.v[j]
+	}
+
+	/// Get the transpose of this matrix, $A'^T + vu^T$
+	pub fn transpose(&self) -> BandPlusRankOne<E, U, L> {
+		BandPlusRankOne::new(self.a_prime.transpose(), self.v.clone(), self.u.clone())
+	}
+}
+
+impl<E: Num + Copy + MaybeSimd, const L: usize, const U: usize> BandPlusRankOne<E, L, U> {
+	/// Get the determinant of this matrix using the matrix determinant lemma,
+	/// $\det(A' + uv^T) = \det(A')(1 + v^TA'^{-1}u)$. This method assumes that
+	/// $A'$ admits LU decomposition.
+	pub fn det(&self) -> E {
+		self.clone().factorize().det()
+	}
+
+	/// Factorize this matrix for solving systems of equations with it
+	pub fn factorize(self) -> ShermanMorrison<E, L, U> {
+		ShermanMorrison::new(self.a_prime, self.u, self.v)
+	}
+}
+
+impl<E: Num + Copy, const L: usize, const U: usize> Mul<&Vector<E>> for &BandPlusRankOne<E, L, U> {
+	type Output = Vector<E>;
+
+	#[allow(clippy::suspicious_arithmetic_impl)]
+	fn mul(self, rhs: &Vector<E>) -> Self::Output {
+		// $Ax = A'x + u(v^Tx)$
+		let vx = self.v.dot(rhs);
+		let ax = &self.a_prime * rhs;
+
+		Vector::from_iter(
+			ax.into_iter()
+				.zip(self.u.iter())
+				.map(|(ax, &u)| ax + u * vx),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		convergence::Criteria,
+		operator::LinearOperator,
+		test_matrices::{num4_b, num4_b_nalgebra, num4_matrix, num4_matrix_nalgebra, num4_u},
+	};
+
+	#[test]
+	fn band_plus_rank_one() {
+		let n = 40;
+		let u = Vector::from_iter((1..=n).map(|i| 1.0 / i as f64));
+		let v = Vector::from_iter((1..=n).map(|i| (i % 3) as f64));
+		let a = BandPlusRankOne::new(num4_matrix(n), u.clone(), v.clone());
+
+		let mut nal = num4_matrix_nalgebra(n).add_scalar(-1.0);
+
+		for i in 1..=n {
+			for j in 1..=n {
+				nal[(i - 1, j - 1)] += u[i] * v[j];
+				assert_eq!(a.get(i, j), nal[(i - 1, j - 1)]);
+				assert_eq!(a.transpose().get(j, i), a.get(i, j));
+			}
+		}
+
+		let x = num4_b(n);
+		let ax = &a * &x;
+		let atx = &a.transpose() * &x;
+		let axnal = &nal * num4_b_nalgebra(n);
+		let atxnal = nal.transpose() * num4_b_nalgebra(n);
+
+		for i in 1..=n {
+			assert!((ax[i] - axnal[i - 1]).abs() < 1e-12);
+			assert!((atx[i] - atxnal[i - 1]).abs() < 1e-12);
+		}
+
+		let det = a.det();
+		assert!((det - nal.determinant()).abs() <= 1e-12 * det.abs());
+
+		// The residual of the solution is (almost) zero
+		let b = num4_b(n);
+		let solution = a.clone().factorize().solve(&b);
+		let residual = &a * &solution - b;
+		assert!(residual.iter().all(|r| r.abs() < 1e-12));
+	}
+
+	#[test]
+	fn gmres() {
+		let n = 80;
+		let a = BandPlusRankOne::new(num4_matrix(n), num4_u(n), num4_u(n));
+		let b = num4_b(n);
+		let exact = a.clone().factorize().solve(&b);
+
+		let res = a.gmres(
+			Vector::new(n),
+			&b,
+			20,
+			Criteria {
+				epsilon: 1e-12,
+				max_iters: 200,
+			},
+		);
+
+		assert!(res.converged);
+		assert!((&res.x - &exact).norm() < 1e-9);
+		assert!(a.residual(&res.x, &b).norm() <= 1e-12 * b.norm());
+	}
+}
@@ -0,0 +1,336 @@
+use core::{
+	cmp::Ordering,
+	error::Error,
+	fmt::{Debug, Display, Formatter, Result as FmtResult},
+};
+
+use num_traits::{real::Real, Num};
+
+use crate::{
+	dense,
+	matrix::{BandMatrix, LuMatrices},
+	parallel::MaybeSync,
+	rank_one::BandPlusRankOne,
+	simd::MaybeSimd,
+	vector::Vector,
+};
+
+/// A reusable factorization of $A = A' + uv^T$, where $A'$ is a band matrix,
+/// for solving $Ax = b$ with the Sherman-Morrison formula. Building it costs
+/// one LU decomposition and one band solve, and every solve afterwards costs
+/// one band solve.
+#[derive(Clone)]
+pub struct ShermanMorrison<E, const L: usize, const U: usize> {
+	lu: LuMatrices<E, L, U>,
+	v: Vector<E>,
+	z: Vector<E>,
+	denominator: E,
+}
+
+/// The reason why the Sherman-Morrison formula can't be used to reliably solve
+/// a system of equations
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShermanMorrisonError<E> {
+	/// The LU decomposition of $A'$ has a zero or non-finite pivot, so $A'$ is
+	/// singular or doesn't admit LU decomposition without pivoting
+	SingularBand,
+	/// The denominator $1 + v^TA'^{-1}u$ is zero or too small relative to the
+	/// magnitude of the terms it's computed from
+	Breakdown {
+		/// The denominator $1 + v^TA'^{-1}u$
+		denominator: E,
+		/// The estimated amplification of rounding errors,
+		/// $κ = \frac{1 + ||v|| ||A'^{-1}u||}{|1 + v^TA'^{-1}u|}$
+		amplification: E,
+	},
+	/// $A' + uv^T$ itself is singular (or has NaN elements), so the dense
+	/// fallback of [`solve_robust`] fails too
+	Singular,
+}
+
+impl<E: Display> Display for ShermanMorrisonError<E> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			Self::SingularBand => write!(f, "A' has a zero pivot in its LU decomposition"),
+			Self::Breakdown {
+				denominator,
+				amplification,
+			} => write!(
+				f,
+				"Sherman-Morrison breakdown: 1 + vᵀA'⁻¹u = {denominator} amplifies errors by {amplification}"
+			),
+			Self::Singular => write!(f, "A' + uvᵀ is singular"),
+		}
+	}
+}
+
+impl<E: Debug + Display> Error for ShermanMorrisonError<E> {}
+
+impl<E: Num + Copy + MaybeSimd, const L: usize, const U: usize> ShermanMorrison<E, L, U> {
+	/// Factorize $A' + uv^T$
+	pub fn new(a_prime: BandMatrix<E, L, U>, u: Vector<E>, v: Vector<E>) -> Self {
+		assert_eq!(a_prime.n(), u.n(), "A''s height must equal u's size");
+		assert_eq!(a_prime.n(), v.n(), "A''s height must equal v's size");
+
+		// $A' = LU$
+		let lu = a_prime.lu_decompose();
+
+		// $LUz = u$
+		let mut z = u;
+		lu.solve_in_place(&mut z);
+
+		// $1 + v^Tz$
+		let denominator = E::one() + v.dot(&z);
+
+		Self {
+			lu,
+			v,
+			z,
+			denominator,
+		}
+	}
+
+	/// Get the size of the factorized matrix
+	pub fn n(&self) -> usize {
+		self.v.n()
+	}
+
+	/// Get the determinant of $A' + uv^T$ using the matrix determinant lemma,
+	/// $\det(A' + uv^T) = \det(A')(1 + v^TA'^{-1}u)$
+	pub fn det(&self) -> E {
+		self.lu.det() * self.denominator
+	}
+
+	/// Solve $(A' + uv^T)x = b$ for $x$
+	pub fn solve(&self, b: &Vector<E>) -> Vector<E> {
+		let mut x = b.clone();
+		self.solve_in_place(&mut x);
+		x
+	}
+
+	/// Solve $(A' + uv^T)x = b$ for $x$, overwriting `b` with `x`
+	pub fn solve_in_place(&self, b: &mut Vector<E>) {
+		assert_eq!(self.n(), b.n(), "A's height must equal b's size");
+
+		// $LUy = b$
+		self.lu.solve_in_place(b);
+		self.update(b);
+	}
+
+	/// Overwrite the solution $y$ of $A'y = b$ with the solution $x$ of
+	/// $(A' + uv^T)x = b$
+	fn update(&self, y: &mut Vector<E>) {
+		update(y, &self.z, &self.v, self.denominator);
+	}
+}
+
+/// Overwrite the solution $y$ of $A'y = b$ with the solution $x$ of
+/// $(A' + uv^T)x = b$, given $z = A'^{-1}u$ and the denominator $1 + v^Tz$
+pub(crate) fn update<E: Num + Copy>(
+	y: &mut Vector<E>,
+	z: &Vector<E>,
+	v: &Vector<E>,
+	denominator: E,
+) {
+	// $v^Ty$
+	let vy = v.dot(y);
+
+	// $x = y - \frac{zv^Ty}{1 + v^Tz}$
+	for i in 1..=y.n() {
+		y[i] = y[i] - z[i] * vy / denominator;
+	}
+}
+
+impl<E: Num + Copy + MaybeSync + MaybeSimd, const L: usize, const U: usize>
+	ShermanMorrison<E, L, U>
+{
+	/// Factorize $A' + uv^T$ and solve $(A' + uv^T)x = b$ for $x$. The systems
+	/// $A'y = b$ and $A'z = u$ are independent, so they are solved in parallel
+	/// if the `rayon` feature is enabled.
+	pub fn new_solving(
+		a_prime: BandMatrix<E, L, U>,
+		u: Vector<E>,
+		v: Vector<E>,
+		b: Vector<E>,
+	) -> (Self, Vector<E>) {
+		assert_eq!(a_prime.n(), u.n(), "A''s height must equal u's size");
+		assert_eq!(a_prime.n(), v.n(), "A''s height must equal v's size");
+		assert_eq!(a_prime.n(), b.n(), "A''s height must equal b's size");
+
+		// $A' = LU$
+		let lu = a_prime.lu_decompose();
+
+		// $LUy = b$, $LUz = u$
+		let mut rhs = [b, u];
+		lu.solve_many_in_place(&mut rhs);
+		let [mut x, z] = rhs;
+
+		// $1 + v^Tz$
+		let denominator = E::one() + v.dot(&z);
+
+		let res = Self {
+			lu,
+			v,
+			z,
+			denominator,
+		};
+		res.update(&mut x);
+		(res, x)
+	}
+}
+
+impl<E: Real + MaybeSimd, const L: usize, const U: usize> ShermanMorrison<E, L, U> {
+	/// Factorize $A' + uv^T$, checking that the Sherman-Morrison formula is
+	/// numerically safe to use. Fails if the LU decomposition of $A'$ has a
+	/// zero pivot or if $|1 + v^TA'^{-1}u| \le τ (1 + ||v|| ||A'^{-1}u||)$ for
+	/// the relative `tolerance` τ (like $\sqrt{ε}$), i.e. if rounding errors
+	/// could be amplified more than $\frac{1}{τ}$ times.
+	pub fn try_new(
+		a_prime: BandMatrix<E, L, U>,
+		u: Vector<E>,
+		v: Vector<E>,
+		tolerance: E,
+	) -> Result<Self, ShermanMorrisonError<E>> {
+		let res = Self::new(a_prime, u, v);
+
+		// Written to also catch NaN pivots
+		let usable = |e: E| e.abs() > E::zero() && e.abs() <= E::max_value();
+
+		if !(1..=res.n()).all(|k| usable(res.lu[(k, k)])) {
+			return Err(ShermanMorrisonError::SingularBand);
+		}
+
+		let amplification = res.amplification();
+
+		// Written to also catch a NaN amplification
+		if (amplification * tolerance).partial_cmp(&E::one()) != Some(Ordering::Less) {
+			return Err(ShermanMorrisonError::Breakdown {
+				denominator: res.denominator,
+				amplification,
+			});
+		}
+
+		Ok(res)
+	}
+
+	/// Estimate how much the Sherman-Morrison formula amplifies rounding
+	/// errors, $κ = \frac{1 + ||v|| ||A'^{-1}u||}{|1 + v^TA'^{-1}u|}$
+	pub fn amplification(&self) -> E {
+		(E::one() + self.v.norm() * self.z.norm()) / self.denominator.abs()
+	}
+}
+
+/// Solve $(A' + uv^T)x = b$ for $x$ using the Sherman-Morrison formula if it's
+/// numerically safe (see [`ShermanMorrison::try_new`]), falling back to
+/// Gaussian elimination with partial pivoting of the full dense matrix
+/// (requiring `O(n²)` memory and `O(n³)` time) otherwise. Fails with
+/// [`ShermanMorrisonError::Singular`] if the fallback finds $A' + uv^T$
+/// singular.
+pub fn solve_robust<E: Real + MaybeSimd, const L: usize, const U: usize>(
+	a_prime: BandMatrix<E, L, U>,
+	u: Vector<E>,
+	v: Vector<E>,
+	b: Vector<E>,
+	tolerance: E,
+) -> Result<Vector<E>, ShermanMorrisonError<E>> {
+	assert_eq!(a_prime.n(), b.n(), "A''s height must equal b's size");
+
+	match ShermanMorrison::try_new(a_prime.clone(), u.clone(), v.clone(), tolerance) {
+		Ok(sm) => {
+			let mut x = b;
+			sm.solve_in_place(&mut x);
+			Ok(x)
+		}
+		Err(_) => {
+			let a = BandPlusRankOne::new(a_prime, u, v);
+			let rows = (1..=a.n())
+				.map(|i| (1..=a.n()).map(|j| a.get(i, j)).collect())
+				.collect();
+
+			dense::solve(rows, b.into_iter().collect())
+				.map(Vector::from_iter)
+				.ok_or(ShermanMorrisonError::Singular)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use nalgebra::DVector;
+
+	use super::*;
+	use crate::test_matrices::{num4_matrix, num4_matrix_nalgebra, num4_u};
+
+	#[test]
+	fn sherman_morrison_reuse() {
+		let n = 80;
+		let ones = num4_u(n);
+		let sm = ShermanMorrison::new(num4_matrix(n), ones.clone(), ones.clone());
+		let nal = num4_matrix_nalgebra(n).lu();
+
+		for k in 1..=5 {
+			let b = Vector::from_iter((1..=n).map(|i| (i * k) as f64));
+			let bnal = DVector::from_iterator(n, (1..=n).map(|i| (i * k) as f64));
+
+			let x = sm.solve(&b);
+			let xnal = nal.solve(&bnal).unwrap();
+
+			let mut y = b.clone();
+			sm.solve_in_place(&mut y);
+			let (_, z) =
+				ShermanMorrison::new_solving(num4_matrix(n), ones.clone(), ones.clone(), b);
+
+			for i in 1..=n {
+				assert!((x[i] - xnal[i - 1]).abs() < 1e-12);
+				assert_eq!(x[i], y[i]);
+				assert_eq!(x[i], z[i]);
+			}
+		}
+	}
+
+	#[test]
+	fn sherman_morrison_breakdown() {
+		let tolerance = f64::EPSILON.sqrt();
+
+		// $A'$ needs pivoting, but $A' + uv^T$ doesn't
+		let mut a_prime = BandMatrix::<f64, 1, 1>::new(3);
+		a_prime[(1, 2)] = 1.0;
+		a_prime[(2, 1)] = 1.0;
+		a_prime[(2, 2)] = 2.0;
+		a_prime[(3, 3)] = 2.0;
+		let e1 = Vector::from_iter([1.0, 0.0, 0.0]);
+		let b = Vector::from_iter([3.0, 5.0, 4.0]);
+
+		assert_eq!(
+			ShermanMorrison::try_new(a_prime.clone(), e1.clone(), e1.clone(), tolerance).err(),
+			Some(ShermanMorrisonError::SingularBand)
+		);
+
+		let x = solve_robust(a_prime, e1.clone(), e1.clone(), b.clone(), tolerance);
+		assert_eq!(x, Ok(Vector::from_iter([1.0, 2.0, 2.0])));
+
+		// Neither $A'$ nor $A' + uv^T = e_1 e_1^T$ is invertible
+		let zero = BandMatrix::<f64, 1, 1>::new(3);
+		assert_eq!(
+			solve_robust(zero, e1.clone(), e1, b, tolerance),
+			Err(ShermanMorrisonError::Singular)
+		);
+
+		// $1 + v^TA'^{-1}u = 0$, so $A' + uv^T$ is singular
+		let a_prime = num4_matrix(10);
+		let u = num4_u(10);
+		let z = a_prime.clone().lu_decompose().solve(&u);
+		let v = Vector::from_iter([-1.0 / z[1], 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+
+		match ShermanMorrison::try_new(a_prime, u, v, tolerance) {
+			Err(ShermanMorrisonError::Breakdown {
+				denominator,
+				amplification,
+			}) => {
+				assert!(denominator.abs() < 1e-15);
+				assert!(amplification > 1.0 / tolerance);
+			}
+			_ => panic!("expected a breakdown"),
+		}
+	}
+}
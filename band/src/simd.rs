@@ -0,0 +1,238 @@
+use core::ops::Add;
+#[cfg(feature = "simd")]
+use core::{
+	any::TypeId,
+	ops::{Mul, Sub},
+	slice,
+};
+
+use num_traits::Num;
+#[cfg(feature = "simd")]
+use num_traits::Zero;
+#[cfg(feature = "simd")]
+use wide::{f32x8, f64x4};
+
+/// The minimum number of elements above/right of the main diagonal from which
+/// [`crate::BandMatrix::lu_decompose`] uses the row-wise SIMD kernels
+pub(crate) const MIN_SIMD_BANDWIDTH: usize = 8;
+
+/// A marker trait for types which can be dispatched to the explicit SIMD
+/// kernels for `f32` and `f64` if the `simd` feature is enabled. Telling the
+/// types apart needs their [`TypeId`], so with the feature enabled the scalars
+/// of [`crate::BandMatrix::lu_decompose`] and the iterative methods must be
+/// `'static`.
+#[cfg(feature = "simd")]
+pub trait MaybeSimd: 'static {}
+
+#[cfg(feature = "simd")]
+impl<T: 'static> MaybeSimd for T {}
+
+/// A marker trait for types which can be dispatched to the explicit SIMD
+/// kernels for `f32` and `f64` if the `simd` feature is enabled, which it
+/// isn't, so it is implemented for all types
+#[cfg(not(feature = "simd"))]
+pub trait MaybeSimd {}
+
+#[cfg(not(feature = "simd"))]
+impl<T> MaybeSimd for T {}
+
+/// Get the dot product $a^T b$, processing several elements at once if the
+/// `simd` feature is enabled and `E` is `f32` or `f64`
+pub(crate) fn dot<E: Num + Copy + MaybeSimd>(a: &[E], b: &[E]) -> E {
+	assert_eq!(a.len(), b.len());
+
+	#[cfg(feature = "simd")]
+	{
+		if let Some(a) = cast::<E, f64>(a) {
+			return uncast(dot_lanes::<f64x4, 4>(a, cast(b).unwrap()));
+		}
+
+		if let Some(a) = cast::<E, f32>(a) {
+			return uncast(dot_lanes::<f32x8, 8>(a, cast(b).unwrap()));
+		}
+	}
+
+	a.iter()
+		.zip(b)
+		.map(|(&a, &b)| a * b)
+		.reduce(Add::add)
+		.unwrap_or_else(E::zero)
+}
+
+/// Get the dot product $a^T b$ with `N` elements processed at once by `V`
+#[cfg(feature = "simd")]
+fn dot_lanes<V, const N: usize>(a: &[V::Element], b: &[V::Element]) -> V::Element
+where
+	V: Lanes<N> + Add<Output = V> + Mul<Output = V>,
+{
+	let mut a_chunks = a.chunks_exact(N);
+	let mut b_chunks = b.chunks_exact(N);
+	let mut sum = V::splat(V::Element::zero());
+
+	for (a, b) in (&mut a_chunks).zip(&mut b_chunks) {
+		sum = sum + V::load(a) * V::load(b);
+	}
+
+	a_chunks
+		.remainder()
+		.iter()
+		.zip(b_chunks.remainder())
+		.map(|(&a, &b)| a * b)
+		.fold(sum.store().into_iter().reduce(Add::add).unwrap(), Add::add)
+}
+
+/// $y = y - ax$, processing several elements at once if the `simd` feature is
+/// enabled and `E` is `f32` or `f64`
+pub(crate) fn sub_scaled<E: Num + Copy + MaybeSimd>(y: &mut [E], a: E, x: &[E]) {
+	assert_eq!(y.len(), x.len());
+
+	#[cfg(feature = "simd")]
+	{
+		if let Some(y) = cast_mut::<E, f64>(y) {
+			return sub_scaled_lanes::<f64x4, 4>(y, uncast(a), cast(x).unwrap());
+		}
+
+		if let Some(y) = cast_mut::<E, f32>(y) {
+			return sub_scaled_lanes::<f32x8, 8>(y, uncast(a), cast(x).unwrap());
+		}
+	}
+
+	for (y, &x) in y.iter_mut().zip(x) {
+		*y = *y - a * x;
+	}
+}
+
+/// $y = y - ax$ with `N` elements processed at once by `V`
+#[cfg(feature = "simd")]
+fn sub_scaled_lanes<V, const N: usize>(y: &mut [V::Element], a: V::Element, x: &[V::Element])
+where
+	V: Lanes<N> + Sub<Output = V> + Mul<Output = V>,
+{
+	let a_lanes = V::splat(a);
+	let (y_chunks, y_rest) = y.split_at_mut(y.len() / N * N);
+	let (x_chunks, x_rest) = x.split_at(y_chunks.len());
+
+	for (y, x) in y_chunks.chunks_exact_mut(N).zip(x_chunks.chunks_exact(N)) {
+		let res = V::load(y) - a_lanes * V::load(x);
+		y.copy_from_slice(&res.store());
+	}
+
+	for (y, &x) in y_rest.iter_mut().zip(x_rest) {
+		*y = *y - a * x;
+	}
+}
+
+/// A SIMD vector of `N` elements
+#[cfg(feature = "simd")]
+trait Lanes<const N: usize>: Copy {
+	type Element: Num + Copy;
+
+	fn splat(value: Self::Element) -> Self;
+	fn load(elements: &[Self::Element]) -> Self;
+	fn store(self) -> [Self::Element; N];
+}
+
+#[cfg(feature = "simd")]
+macro_rules! impl_lanes {
+	($lanes:ty, $element:ty, $n:literal) => {
+		impl Lanes<$n> for $lanes {
+			type Element = $element;
+
+			#[inline]
+			fn splat(value: $element) -> Self {
+				<$lanes>::splat(value)
+			}
+
+			#[inline]
+			fn load(elements: &[$element]) -> Self {
+				<$lanes>::from(<[$element; $n]>::try_from(elements).unwrap())
+			}
+
+			#[inline]
+			fn store(self) -> [$element; $n] {
+				self.to_array()
+			}
+		}
+	};
+}
+
+#[cfg(feature = "simd")]
+impl_lanes!(f64x4, f64, 4);
+#[cfg(feature = "simd")]
+impl_lanes!(f32x8, f32, 8);
+
+/// Reinterpret `slice` as a slice of `T`, if `E` is `T`
+#[cfg(feature = "simd")]
+fn cast<E: 'static, T: 'static>(slice: &[E]) -> Option<&[T]> {
+	// SAFETY: `E` and `T` are the same type
+	(TypeId::of::<E>() == TypeId::of::<T>())
+		.then(|| unsafe { slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) })
+}
+
+/// Reinterpret `slice` as a mutable slice of `T`, if `E` is `T`
+#[cfg(feature = "simd")]
+fn cast_mut<E: 'static, T: 'static>(slice: &mut [E]) -> Option<&mut [T]> {
+	// SAFETY: `E` and `T` are the same type
+	(TypeId::of::<E>() == TypeId::of::<T>())
+		.then(|| unsafe { slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len()) })
+}
+
+/// Reinterpret `value` as an `E`, panicking if `T` is not `E`
+#[cfg(feature = "simd")]
+fn uncast<T: 'static, E: Copy + 'static>(value: T) -> E {
+	cast(slice::from_ref(&value)).unwrap()[0]
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{dot, sub_scaled};
+
+	#[test]
+	fn dot_matches_scalar() {
+		for len in 0..=20 {
+			let a = (0..len).map(|i| i as f64 / 3.0).collect::<Vec<_>>();
+			let b = (0..len).map(|i| (i * i) as f64).collect::<Vec<_>>();
+			let expected = a.iter().zip(&b).map(|(a, b)| a * b).sum::<f64>();
+			assert!((dot(&a, &b) - expected).abs() <= 1e-12 * expected);
+
+			let a = a.iter().map(|&a| a as f32).collect::<Vec<_>>();
+			let b = b.iter().map(|&b| b as f32).collect::<Vec<_>>();
+			let expected = a.iter().zip(&b).map(|(a, b)| a * b).sum::<f32>();
+			assert!((dot(&a, &b) - expected).abs() <= 1e-5 * expected);
+
+			let a = (0..len).collect::<Vec<i32>>();
+			assert_eq!(dot(&a, &a), (0..len).map(|i| i * i).sum());
+		}
+	}
+
+	#[test]
+	fn sub_scaled_matches_scalar() {
+		for len in 0..=20 {
+			let x = (0..len).map(|i| i as f64 / 3.0).collect::<Vec<_>>();
+			let mut y = (0..len).map(|i| (i * i) as f64).collect::<Vec<_>>();
+			let expected = y
+				.iter()
+				.zip(&x)
+				.map(|(y, x)| y - 1.5 * x)
+				.collect::<Vec<_>>();
+
+			sub_scaled(&mut y, 1.5, &x);
+			assert_eq!(y, expected);
+
+			let x = x.iter().map(|&x| x as f32).collect::<Vec<_>>();
+			let mut y = (0..len).map(|i| (i * i) as f32).collect::<Vec<_>>();
+			let expected = y
+				.iter()
+				.zip(&x)
+				.map(|(y, x)| y - 1.5 * x)
+				.collect::<Vec<_>>();
+
+			sub_scaled(&mut y, 1.5, &x);
+			assert_eq!(y, expected);
+
+			let mut y = (0..len).collect::<Vec<i32>>();
+			sub_scaled(&mut y, 2, &(0..len).collect::<Vec<_>>());
+			assert_eq!(y, (0..len).map(|i| -i).collect::<Vec<_>>());
+		}
+	}
+}
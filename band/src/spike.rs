@@ -0,0 +1,303 @@
+use alloc::{vec, vec::Vec};
+
+use num_traits::Num;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{
+	block::{BlockBandMatrix, BlockLuMatrices},
+	matrix::{BandMatrix, LuMatrices},
+	parallel::MaybeSync,
+	simd::MaybeSimd,
+	vector::Vector,
+};
+
+/// A factorization of a square Band Matrix for the
+/// [SPIKE algorithm](https://doi.org/10.1016/j.compfluid.2005.07.005), which
+/// splits the matrix into `p` diagonal blocks $A_j$ coupled by the blocks
+/// $B_j$ (above/right of $A_j$) and $C_j$ (below/left of $A_j$). The diagonal
+/// blocks are factorized independently (in parallel if the `rayon` feature is
+/// enabled), and the coupling is resolved by a small block tridiagonal reduced
+/// system of size `p(L + U)`.
+///
+/// Like [`BandMatrix::lu_decompose`], this assumes that no pivoting is needed
+/// (e.g. that the matrix is diagonally dominant). For such matrices, the
+/// results agree with the sequential LU solve up to a relative error of the
+/// order of the condition number times the machine epsilon.
+pub struct Spike<E, const L: usize, const U: usize> {
+	partitions: Vec<Partition<E, L, U>>,
+	reduced: Option<BlockLuMatrices<E, 1, 1>>,
+}
+
+/// One diagonal block $A_j$ of a [`Spike`] factorization
+struct Partition<E, const L: usize, const U: usize> {
+	/// The global index of the first row of this block
+	start: usize,
+	/// The number of rows of this block
+	m: usize,
+	lu: LuMatrices<E, L, U>,
+	/// The `U` columns of the right spike $V_j = A_j^{-1} [ 0 B_j ]^T$
+	v: Vec<Vector<E>>,
+	/// The `L` columns of the left spike $W_j = A_j^{-1} [ C_j 0 ]^T$
+	w: Vec<Vector<E>>,
+}
+
+impl<E: Num + Copy + MaybeSync + MaybeSimd, const L: usize, const U: usize> BandMatrix<E, L, U> {
+	/// Factorize this matrix for the SPIKE algorithm using `p` partitions,
+	/// each of which needs to have at least `L + U` rows
+	pub fn spike(&self, p: usize) -> Spike<E, L, U> {
+		let n = self.n();
+
+		assert_ne!(p, 0, "SPIKE needs at least one partition");
+		assert!(
+			p == 1 || n / p >= L + U,
+			"Can't split a {n}x{n} BandMatrix<_, {L}, {U}> into {p} partitions of at least {} rows",
+			L + U
+		);
+
+		// The first `n % p` partitions get one extra row
+		let start = |j: usize| j * (n / p) + j.min(n % p) + 1;
+
+		let partitions = map_partitions(p, |j| {
+			let (first, last) = (start(j), start(j + 1) - 1);
+			let m = last - first + 1;
+
+			// $A_j$
+			let mut a = BandMatrix::new(m);
+
+			for i in first..=last {
+				for k in first.max(i.saturating_sub(L))..=(i + U).min(last) {
+					a[(i - first + 1, k - first + 1)] = self[(i, k)];
+				}
+			}
+
+			let lu = a.lu_decompose();
+
+			// The `c`th column of $[ 0 B_j ]^T$ is column `last + c` of the
+			// matrix, restricted to this partition's rows
+			let v = if j + 1 < p {
+				(1..=U)
+					.map(|c| {
+						let column = Vector::from_iter((first..=last).map(|i| {
+							if i + U >= last + c {
+								self[(i, last + c)]
+							} else {
+								E::zero()
+							}
+						}));
+
+						lu.solve(&column)
+					})
+					.collect()
+			} else {
+				vec![]
+			};
+
+			// The `c`th column of $[ C_j 0 ]^T$ is column `first - L - 1 + c`
+			// of the matrix, restricted to this partition's rows
+			let w = if j > 0 {
+				(1..=L)
+					.map(|c| {
+						let column = Vector::from_iter((first..=last).map(|i| {
+							if i < first + c {
+								self[(i, first - L - 1 + c)]
+							} else {
+								E::zero()
+							}
+						}));
+
+						lu.solve(&column)
+					})
+					.collect()
+			} else {
+				vec![]
+			};
+
+			Partition {
+				start: first,
+				m,
+				lu,
+				v,
+				w,
+			}
+		});
+
+		// The reduced system for the top `U` and bottom `L` elements
+		// $y_j = [ x_j^t x_j^b ]^T$ of every partition's part of $x$,
+		// $y_j + [ 0 W_j^{t,b} ] y_{j-1} + [ V_j^{t,b} 0 ] y_{j+1} = [ g_j^t g_j^b ]^T$
+		let k = L + U;
+		let reduced = (k > 0 && p > 1).then(|| {
+			let mut reduced = BlockBandMatrix::<E, 1, 1>::new(p, k);
+
+			for (j, partition) in partitions.iter().enumerate() {
+				let rows = partition.reduced_rows();
+
+				let block = reduced.block_mut(j + 1, j + 1);
+				for r in 0..k {
+					block[r * k + r] = E::one();
+				}
+
+				if j + 1 < p {
+					let block = reduced.block_mut(j + 1, j + 2);
+
+					for (r, &row) in rows.iter().enumerate() {
+						for (c, v) in partition.v.iter().enumerate() {
+							block[r * k + c] = v[row];
+						}
+					}
+				}
+
+				if j > 0 {
+					let block = reduced.block_mut(j + 1, j);
+
+					for (r, &row) in rows.iter().enumerate() {
+						for (c, w) in partition.w.iter().enumerate() {
+							block[r * k + U + c] = w[row];
+						}
+					}
+				}
+			}
+
+			reduced.lu_decompose()
+		});
+
+		Spike {
+			partitions,
+			reduced,
+		}
+	}
+}
+
+impl<E, const L: usize, const U: usize> Partition<E, L, U> {
+	/// Get the local indices of the top `U` and bottom `L` rows
+	fn reduced_rows(&self) -> Vec<usize> {
+		(1..=U).chain(self.m - L + 1..=self.m).collect()
+	}
+}
+
+impl<E: Num + Copy + MaybeSync + MaybeSimd, const L: usize, const U: usize> Spike<E, L, U> {
+	/// Get the number of partitions
+	pub fn partitions(&self) -> usize {
+		self.partitions.len()
+	}
+
+	/// Solve $Ax = b$ for $x$
+	pub fn solve(&self, b: &Vector<E>) -> Vector<E> {
+		assert_eq!(
+			self.partitions.iter().map(|p| p.m).sum::<usize>(),
+			b.n(),
+			"A's height must equal b's size"
+		);
+
+		let p = self.partitions.len();
+		let k = L + U;
+
+		// $A_j g_j = b_j$
+		let g = map_partitions(p, |j| {
+			let partition = &self.partitions[j];
+			let rows = partition.start..partition.start + partition.m;
+
+			partition.lu.solve(&Vector::from_iter(rows.map(|i| b[i])))
+		});
+
+		// Solve the reduced system for $y_j = [ x_j^t x_j^b ]^T$
+		let y =
+			self.reduced.as_ref().map(|reduced| {
+				let rhs =
+					Vector::from_iter(self.partitions.iter().zip(&g).flat_map(|(partition, g)| {
+						partition.reduced_rows().into_iter().map(|r| g[r])
+					}));
+
+				reduced.solve(&rhs)
+			});
+
+		// $x_j = g_j - V_j x_{j+1}^t - W_j x_{j-1}^b$
+		let parts = map_partitions(p, |j| {
+			let partition = &self.partitions[j];
+			let g = &g[j];
+
+			Vector::from_iter((1..=g.n()).map(|r| {
+				let mut x = g[r];
+
+				if let Some(y) = &y {
+					for (c, v) in partition.v.iter().enumerate() {
+						x = x - v[r] * y[(j + 1) * k + c + 1];
+					}
+
+					for (c, w) in partition.w.iter().enumerate() {
+						x = x - w[r] * y[(j - 1) * k + U + c + 1];
+					}
+				}
+
+				x
+			}))
+		});
+
+		Vector::from_iter(parts.iter().flat_map(|x| (1..=x.n()).map(|r| x[r])))
+	}
+}
+
+/// Compute `f(j)` for every partition `j = 0, ..., p - 1` (in parallel if the
+/// `rayon` feature is enabled)
+fn map_partitions<T: MaybeSync>(p: usize, f: impl Fn(usize) -> T + MaybeSync) -> Vec<T> {
+	#[cfg(feature = "rayon")]
+	let partitions = (0..p).into_par_iter();
+	#[cfg(not(feature = "rayon"))]
+	let partitions = 0..p;
+
+	partitions.map(f).collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		test_matrices::{num3_matrix, num3_vector},
+		BandMatrix, Vector,
+	};
+
+	fn relative_error(x: &Vector<f64>, y: &Vector<f64>) -> f64 {
+		let diff = (1..=x.n()).map(|i| (x[i] - y[i]).powi(2)).sum::<f64>();
+		let norm = (1..=y.n()).map(|i| y[i].powi(2)).sum::<f64>();
+
+		(diff / norm).sqrt()
+	}
+
+	#[test]
+	fn matches_lu_solve() {
+		let n = 1000;
+		let a = num3_matrix(n);
+		let b = num3_vector(n);
+		let expected = a.clone().lu_decompose().solve(&b);
+
+		for p in [1, 2, 3, 7, 16, 333] {
+			let x = a.spike(p).solve(&b);
+
+			assert_eq!(x.n(), n);
+			assert!(relative_error(&x, &expected) < 1e-12);
+		}
+	}
+
+	#[test]
+	fn wider_bands() {
+		let n = 200;
+		let mut a = BandMatrix::<f64, 3, 2>::new(n);
+
+		for i in 1..=n {
+			for j in 1.max(i.saturating_sub(3))..=(i + 2).min(n) {
+				a[(i, j)] = if i == j {
+					10.0
+				} else {
+					1.0 / (i + 2 * j) as f64
+				};
+			}
+		}
+
+		let b = Vector::from_iter((1..=n).map(|i| (i as f64).sin()));
+		let expected = a.clone().lu_decompose().solve(&b);
+
+		for p in [1, 4, 13, 40] {
+			assert!(relative_error(&a.spike(p).solve(&b), &expected) < 1e-12);
+		}
+	}
+}
@@ -0,0 +1,421 @@
+use core::ops::{Add, Deref};
+
+use num_traits::{real::Real, Num};
+
+use crate::{matrix::BandMatrix, parallel::MaybeSync, simd::MaybeSimd, vector::Vector};
+
+/// A splitting `A = M - N` of a matrix, which defines the stationary iterative
+/// method $x^{(k+1)} = M^{-1}(Nx^{(k)} + b)$
+pub trait Splitting<E> {
+	/// Perform one iteration of the method defined by this splitting of `a`,
+	/// returning the next `x`
+	fn iterate<
+		const L: usize,
+		const U: usize,
+		S: Deref<Target = [E]>,
+		X: Deref<Target = [E]>,
+		B: Deref<Target = [E]>,
+	>(
+		&self,
+		a: &BandMatrix<E, L, U, S>,
+		x: &Vector<E, X>,
+		b: &Vector<E, B>,
+	) -> Vector<E>;
+}
+
+/// The Jacobi method, with $M = D$
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Jacobi;
+
+/// The damped (weighted) Jacobi method with weight ω, with $M = \frac{1}{ω} D$
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DampedJacobi<E>(pub E);
+
+/// The (forward) Gauss-Seidel method, with $M = D + L$
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GaussSeidel;
+
+/// The multicolour Gauss-Seidel method, i.e. the Gauss-Seidel method with the
+/// rows reordered such that rows of the same colour can be updated in parallel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MulticolorGaussSeidel;
+
+/// The backward Gauss-Seidel method, with $M = D + U$
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BackwardGaussSeidel;
+
+/// Successive over-relaxation with relaxation factor ω, with
+/// $M = \frac{1}{ω} D + L$
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sor<E>(pub E);
+
+/// Symmetric successive over-relaxation with relaxation factor ω, i.e. a
+/// forward SOR sweep followed by a backward SOR sweep, with
+/// $M = \frac{1}{ω(2 - ω)} (D + ωL) D^{-1} (D + ωU)$
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ssor<E>(pub E);
+
+impl<E: Num + Copy + MaybeSync + MaybeSimd> Splitting<E> for Jacobi {
+	fn iterate<
+		const L: usize,
+		const U: usize,
+		S: Deref<Target = [E]>,
+		X: Deref<Target = [E]>,
+		B: Deref<Target = [E]>,
+	>(
+		&self,
+		a: &BandMatrix<E, L, U, S>,
+		x: &Vector<E, X>,
+		b: &Vector<E, B>,
+	) -> Vector<E> {
+		a.jacobi_iteration(x, b)
+	}
+}
+
+impl<E: Num + Copy + MaybeSync + MaybeSimd> Splitting<E> for DampedJacobi<E> {
+	fn iterate<
+		const L: usize,
+		const U: usize,
+		S: Deref<Target = [E]>,
+		X: Deref<Target = [E]>,
+		B: Deref<Target = [E]>,
+	>(
+		&self,
+		a: &BandMatrix<E, L, U, S>,
+		x: &Vector<E, X>,
+		b: &Vector<E, B>,
+	) -> Vector<E> {
+		let omega = self.0;
+		let jacobi = a.jacobi_iteration(x, b);
+
+		// $x^{(k+1)} = (1 - ω) x^{(k)} + ω x_{Jacobi}^{(k+1)}$
+		Vector::from_iter(
+			x.iter()
+				.zip(jacobi)
+				.map(|(&x, j)| (E::one() - omega) * x + omega * j),
+		)
+	}
+}
+
+impl<E: Num + Copy + MaybeSimd> Splitting<E> for GaussSeidel {
+	fn iterate<
+		const L: usize,
+		const U: usize,
+		S: Deref<Target = [E]>,
+		X: Deref<Target = [E]>,
+		B: Deref<Target = [E]>,
+	>(
+		&self,
+		a: &BandMatrix<E, L, U, S>,
+		x: &Vector<E, X>,
+		b: &Vector<E, B>,
+	) -> Vector<E> {
+		a.gauss_seidel_iteration(x, b)
+	}
+}
+
+impl<E: Num + Copy + MaybeSync + MaybeSimd> Splitting<E> for MulticolorGaussSeidel {
+	fn iterate<
+		const L: usize,
+		const U: usize,
+		S: Deref<Target = [E]>,
+		X: Deref<Target = [E]>,
+		B: Deref<Target = [E]>,
+	>(
+		&self,
+		a: &BandMatrix<E, L, U, S>,
+		x: &Vector<E, X>,
+		b: &Vector<E, B>,
+	) -> Vector<E> {
+		a.multicolor_gauss_seidel_iteration(x, b)
+	}
+}
+
+impl<E: Num + Copy> Splitting<E> for BackwardGaussSeidel {
+	fn iterate<
+		const L: usize,
+		const U: usize,
+		S: Deref<Target = [E]>,
+		X: Deref<Target = [E]>,
+		B: Deref<Target = [E]>,
+	>(
+		&self,
+		a: &BandMatrix<E, L, U, S>,
+		x: &Vector<E, X>,
+		b: &Vector<E, B>,
+	) -> Vector<E> {
+		let mut next = Vector::from_iter(x.iter().copied());
+		sor_sweep(a, &mut next, b, E::one(), true);
+		next
+	}
+}
+
+impl<E: Num + Copy> Splitting<E> for Sor<E> {
+	fn iterate<
+		const L: usize,
+		const U: usize,
+		S: Deref<Target = [E]>,
+		X: Deref<Target = [E]>,
+		B: Deref<Target = [E]>,
+	>(
+		&self,
+		a: &BandMatrix<E, L, U, S>,
+		x: &Vector<E, X>,
+		b: &Vector<E, B>,
+	) -> Vector<E> {
+		let mut next = Vector::from_iter(x.iter().copied());
+		sor_sweep(a, &mut next, b, self.0, false);
+		next
+	}
+}
+
+impl<E: Num + Copy> Splitting<E> for Ssor<E> {
+	fn iterate<
+		const L: usize,
+		const U: usize,
+		S: Deref<Target = [E]>,
+		X: Deref<Target = [E]>,
+		B: Deref<Target = [E]>,
+	>(
+		&self,
+		a: &BandMatrix<E, L, U, S>,
+		x: &Vector<E, X>,
+		b: &Vector<E, B>,
+	) -> Vector<E> {
+		let mut next = Vector::from_iter(x.iter().copied());
+		sor_sweep(a, &mut next, b, self.0, false);
+		sor_sweep(a, &mut next, b, self.0, true);
+		next
+	}
+}
+
+/// Perform one in-place SOR sweep over `x`, from the first to the last row or
+/// from the last to the first row if `backward` is set
+fn sor_sweep<
+	E: Num + Copy,
+	const L: usize,
+	const U: usize,
+	S: Deref<Target = [E]>,
+	B: Deref<Target = [E]>,
+>(
+	a: &BandMatrix<E, L, U, S>,
+	x: &mut Vector<E>,
+	b: &Vector<E, B>,
+	omega: E,
+	backward: bool,
+) {
+	assert_eq!(a.n(), x.n());
+	assert_eq!(x.n(), b.n());
+
+	let n = x.n();
+	let mut sweep = |i: usize| {
+		// $x_i \leftarrow (1 - ω) x_i + \frac{ω}{a_{i,i}} (b_i - \sum_{j \ne i} a_{i,j}x_j)$,
+		// where the $x_j$ already updated in this sweep are used
+		let sum = (1.max(i.saturating_sub(L))..=(i + U).min(n))
+			.filter(|&j| j != i)
+			.map(|j| a[(i, j)] * x[j])
+			.reduce(Add::add)
+			.unwrap_or_else(E::zero);
+
+		x[i] = (E::one() - omega) * x[i] + omega * (b[i] - sum) / a[(i, i)];
+	};
+
+	if backward {
+		(1..=n).rev().for_each(&mut sweep);
+	} else {
+		(1..=n).for_each(&mut sweep);
+	}
+}
+
+impl<E: Num + Copy, const L: usize, const U: usize, S: Deref<Target = [E]>> BandMatrix<E, L, U, S> {
+	/// Perform one iteration of the method defined by the given splitting,
+	/// returning the next `x`
+	pub fn splitting_iteration<X: Deref<Target = [E]>, B: Deref<Target = [E]>>(
+		&self,
+		splitting: &impl Splitting<E>,
+		x: &Vector<E, X>,
+		b: &Vector<E, B>,
+	) -> Vector<E> {
+		splitting.iterate(self, x, b)
+	}
+}
+
+impl<
+		E: Real + Copy + MaybeSync + MaybeSimd,
+		const L: usize,
+		const U: usize,
+		S: Deref<Target = [E]>,
+	> BandMatrix<E, L, U, S>
+{
+	/// Perform the method defined by the given splitting, returning the result
+	/// if it converged
+	pub fn stationary(
+		&self,
+		splitting: &impl Splitting<E>,
+		mut x: Vector<E>,
+		b: &Vector<E>,
+		epsilon: E,
+		max_iters: usize,
+	) -> Option<Vector<E>> {
+		assert_eq!(self.n(), x.n());
+		assert_eq!(x.n(), b.n());
+
+		let mut next;
+
+		for _ in 0..max_iters {
+			next = self.splitting_iteration(splitting, &x, b);
+
+			if (&x - &next).norm() < epsilon {
+				return Some(next);
+			}
+
+			x = next;
+		}
+
+		None
+	}
+
+	/// Estimate the spectral radius of the Jacobi iteration matrix
+	/// $I - D^{-1}A$ using `iters` steps of the power method. The spectral
+	/// radius of an empty matrix is 0.
+	pub fn jacobi_spectral_radius(&self, iters: usize) -> E {
+		if self.n() == 0 {
+			return E::zero();
+		}
+
+		let zero = Vector::new(self.n());
+		let mut x = Vector::from_iter((1..=self.n()).map(|_| E::one()));
+		let mut rho = E::zero();
+
+		for _ in 0..iters {
+			// A Jacobi iteration with $b = 0$ is a multiplication by $I - D^{-1}A$
+			let next = self.jacobi_iteration(&x, &zero);
+			let norm = next.norm();
+			rho = norm / x.norm();
+
+			if norm == E::zero() {
+				break;
+			}
+
+			x = next / norm;
+		}
+
+		rho
+	}
+
+	/// Estimate the optimal SOR relaxation factor
+	/// $ω_{opt} = \frac{2}{1 + \sqrt{1 - ρ_J^2}}$ from the spectral radius of
+	/// the Jacobi iteration matrix (estimated using `iters` steps of the power
+	/// method), returning `None` if the matrix is empty or the Jacobi method
+	/// does not converge for this matrix. The estimate is exact for
+	/// consistently ordered matrices, like tridiagonal ones.
+	#[allow(clippy::neg_cmp_op_on_partial_ord)]
+	pub fn optimal_sor_omega(&self, iters: usize) -> Option<E> {
+		if self.n() == 0 {
+			return None;
+		}
+
+		let rho = self.jacobi_spectral_radius(iters);
+
+		// Also rejects a NaN estimate
+		if !(rho < E::one()) {
+			return None;
+		}
+
+		let two = E::one() + E::one();
+		Some(two / (E::one() + (E::one() - rho * rho).sqrt()))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_matrices::poisson;
+
+	#[test]
+	fn sor_with_unit_omega_is_gauss_seidel() {
+		let a = poisson(20);
+		let b = Vector::from_iter((1..=20).map(|i| i as f64));
+		let x = Vector::from_iter((1..=20).map(|i| 1.0 / i as f64));
+
+		let gs = a.splitting_iteration(&GaussSeidel, &x, &b);
+		let sor = a.splitting_iteration(&Sor(1.0), &x, &b);
+
+		assert!((&gs - &sor).norm() < 1e-12);
+	}
+
+	#[test]
+	fn splittings_converge() {
+		let a = poisson(20);
+		let b = Vector::from_iter((1..=20).map(|i| i as f64));
+		let exact = a.clone().lu_decompose().solve(&b);
+		let omega = a.optimal_sor_omega(1000).unwrap();
+
+		let solutions = [
+			a.stationary(&DampedJacobi(0.9), Vector::new(20), &b, 1e-12, 10000),
+			a.stationary(&BackwardGaussSeidel, Vector::new(20), &b, 1e-12, 10000),
+			a.stationary(&Sor(omega), Vector::new(20), &b, 1e-12, 10000),
+			a.stationary(&Ssor(1.5), Vector::new(20), &b, 1e-12, 10000),
+		];
+
+		for x in solutions {
+			assert!((&x.unwrap() - &exact).norm() < 1e-8);
+		}
+	}
+
+	#[test]
+	fn multicolor_gauss_seidel() {
+		let a = crate::test_matrices::num5_matrix(1000);
+		let b = crate::test_matrices::num5_b(1000);
+		let exact = a.clone().lu_decompose().solve(&b);
+
+		let mut x = Vector::new(1000);
+		let mut y = Vector::new(1000);
+		let mut iters = (0, 0);
+
+		while (&x - &exact).norm() > 1e-9 {
+			x = a.splitting_iteration(&GaussSeidel, &x, &b);
+			iters.0 += 1;
+		}
+
+		while (&y - &exact).norm() > 1e-9 {
+			y = a.splitting_iteration(&MulticolorGaussSeidel, &y, &b);
+			iters.1 += 1;
+		}
+
+		assert!(iters.1 <= iters.0 + iters.0 / 2);
+	}
+
+	#[test]
+	fn optimal_omega() {
+		// For the 1D Poisson matrix $ρ_J = \cos(\frac{π}{n + 1})$
+		let n = 20;
+		let rho = (core::f64::consts::PI / (n + 1) as f64).cos();
+		let expected = 2.0 / (1.0 + (1.0 - rho * rho).sqrt());
+
+		let a = poisson(n);
+		assert!((a.jacobi_spectral_radius(5000) - rho).abs() < 1e-6);
+		assert!((a.optimal_sor_omega(5000).unwrap() - expected).abs() < 1e-4);
+	}
+
+	#[test]
+	fn empty_matrix() {
+		let a = poisson(0);
+
+		assert_eq!(a.jacobi_spectral_radius(10), 0.0);
+		assert_eq!(a.optimal_sor_omega(10), None);
+	}
+
+	#[test]
+	fn iterate_views() {
+		let a = poisson(20);
+		let b = Vector::from_iter((1..=20).map(|i| i as f64));
+		let x = Vector::from_iter((1..=20).map(|i| 1.0 / i as f64));
+		let expected = a.splitting_iteration(&Ssor(1.5), &x, &b);
+
+		assert_eq!(
+			a.splitting_iteration(&Ssor(1.5), &x.view(), &b.view()),
+			expected
+		);
+	}
+}
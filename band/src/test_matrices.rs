@@ -0,0 +1,71 @@
+//! The systems of equations from the NUM3, NUM4 and NUM5 assignments, and the
+//! 1D Poisson problem, used as test cases
+
+use core::iter;
+
+use nalgebra::{DMatrix, DVector};
+
+use crate::{matrix::BandMatrix, toeplitz::ToeplitzBand, vector::Vector};
+
+/// Generate the matrix from NUM3
+pub(crate) fn num3_matrix(n: usize) -> BandMatrix<f64, 1, 2> {
+	// Match on the diagonal $j - i$, shifted by L = 1
+	BandMatrix::from_fn(n, |i, j| match j + 1 - i {
+		0 => 0.2,
+		1 => 1.2,
+		2 => 0.1 / i as f64,
+		_ => 0.15 / (i as f64).powi(2),
+	})
+}
+
+/// Generate the vector from NUM3
+pub(crate) fn num3_vector(n: usize) -> Vector<f64> {
+	Vector::from_iter((1..=n).map(|x| x as f64))
+}
+
+/// Generate the band matrix A' from NUM4
+pub(crate) fn num4_matrix(n: usize) -> BandMatrix<f64, 0, 1> {
+	BandMatrix::from_constant_diagonals(n, &[12.0 - 1.0, 8.0 - 1.0]).unwrap()
+}
+
+/// Generate the vector u = vᵀ from NUM4
+pub(crate) fn num4_u(n: usize) -> Vector<f64> {
+	Vector::from_iter(iter::repeat_n(1.0, n))
+}
+
+/// Generate the vector b from NUM4
+pub(crate) fn num4_b(n: usize) -> Vector<f64> {
+	Vector::from_iter(iter::repeat_n(5.0, n))
+}
+
+/// Generate the matrix A from NUM4
+pub(crate) fn num4_matrix_nalgebra(n: usize) -> DMatrix<f64> {
+	let a_prime = num4_matrix(n);
+	DMatrix::from_fn(n, n, |i, j| 1.0 + a_prime[(i + 1, j + 1)])
+}
+
+/// Generate the vector b from NUM4
+pub(crate) fn num4_b_nalgebra(n: usize) -> DVector<f64> {
+	DVector::from_element(n, 5.0)
+}
+
+/// Generate the band matrix A from NUM5
+pub(crate) fn num5_matrix(n: usize) -> BandMatrix<f64, 2, 2> {
+	BandMatrix::from_constant_diagonals(n, &[0.15, 1.0, 3.0, 1.0, 0.15]).unwrap()
+}
+
+/// Generate the band matrix A from NUM5 as a Toeplitz band matrix
+pub(crate) fn num5_toeplitz(n: usize) -> ToeplitzBand<f64, 2, 2> {
+	ToeplitzBand::new(n, &[0.15, 1.0, 3.0, 1.0, 0.15]).unwrap()
+}
+
+/// Generate the vector b from NUM5
+pub(crate) fn num5_b(n: usize) -> Vector<f64> {
+	Vector::from_iter((1..=n).map(|i| i as f64))
+}
+
+/// Generate the matrix of the 1D Poisson problem, tridiagonal with $2$ on the
+/// main diagonal and $-1$ next to it
+pub(crate) fn poisson(n: usize) -> BandMatrix<f64, 1, 1> {
+	BandMatrix::from_constant_diagonals(n, &[-1.0, 2.0, -1.0]).unwrap()
+}
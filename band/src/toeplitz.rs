@@ -0,0 +1,451 @@
+use alloc::{boxed::Box, format, vec, vec::Vec};
+use core::{
+	any,
+	fmt::{Debug, Formatter, Result as FmtResult},
+	ops::{Add, Index, Mul},
+};
+
+use num_traits::{real::Real, FloatConst, Num};
+
+use crate::{construct::BandMatrixError, matrix::BandMatrix, vector::Vector};
+
+/// The number of rows of the factors which [`ToeplitzBand::lu_decompose`]
+/// stores at most
+const MAX_STORED_ROWS: usize = 1 << 16;
+
+/// A square Toeplitz [Band Matrix](https://en.wikipedia.org/wiki/Band_matrix)
+/// with L elements below/left of the main diagonal and U elements above/right
+/// of the main diagonal, where every diagonal is constant. Requires only
+/// `O(1)` storage space. Indexing into the matrix works as usual, but is
+/// read-only.
+#[derive(Clone, PartialEq)]
+pub struct ToeplitzBand<E, const L: usize, const U: usize> {
+	n: usize,
+	zero: E,
+	diagonals: Box<[E]>,
+}
+
+/// The LU factors of a square Toeplitz Band Matrix. The rows of the factors
+/// of a Toeplitz matrix only depend on the rows above them, and (for matrices
+/// which admit LU decomposition) converge to a constant row, so only the rows
+/// until they stop changing are stored, one after another, and the last one
+/// stands in for all of the following rows.
+#[derive(Clone, PartialEq)]
+pub struct ToeplitzLu<E, const L: usize, const U: usize> {
+	n: usize,
+	rows: Vec<E>,
+	tail: E,
+}
+
+impl<E, const L: usize, const U: usize> ToeplitzBand<E, L, U> {
+	pub fn n(&self) -> usize {
+		self.n
+	}
+
+	/// Get the value on the diagonal `offset` places above/right of the main
+	/// diagonal (or below/left of it if `offset` is negative)
+	pub fn diagonal(&self, offset: isize) -> &E {
+		assert!(
+			-(L as isize) <= offset && offset <= U as isize,
+			"Diagonal {offset} out of bounds for ToeplitzBand<_, {L}, {U}>"
+		);
+
+		&self.diagonals[(L as isize + offset) as usize]
+	}
+}
+
+impl<E: Num + Copy, const L: usize, const U: usize> ToeplitzBand<E, L, U> {
+	/// Create a new n by n Toeplitz Band Matrix, given the values of its
+	/// `L + U + 1` diagonals, from the lowest to the highest
+	pub fn new(n: usize, diagonals: &[E]) -> Result<Self, BandMatrixError> {
+		if diagonals.len() != L + U + 1 {
+			return Err(BandMatrixError::DiagonalCount {
+				expected: L + U + 1,
+				found: diagonals.len(),
+			});
+		}
+
+		Ok(Self {
+			n,
+			zero: E::zero(),
+			diagonals: diagonals.into(),
+		})
+	}
+
+	/// Convert this matrix into a regular band matrix, which requires `O(n)`
+	/// storage space
+	pub fn to_band(&self) -> BandMatrix<E, L, U> {
+		BandMatrix::from_fn(self.n, |i, j| self[(i, j)])
+	}
+
+	/// Perform LU decomposition of this matrix. This method assumes that the
+	/// matrix admits LU decomposition. Rows of the factors are only stored
+	/// until [`ToeplitzLu::tail_estimate`] falls below machine epsilon
+	/// (relative to the last row), or until 2¹⁶ rows are stored (see
+	/// [`Self::lu_decompose_capped`]).
+	///
+	/// For strictly diagonally dominant matrices, like the one from NUM5, the
+	/// rows converge geometrically, so only a few dozen rows are stored and
+	/// the factors are exact up to rounding. The rows of matrices on the edge
+	/// of diagonal dominance only converge slowly, e.g.
+	/// $u_{k,k} = \frac{k + 1}{k}$ for the diagonals $-1, 2, -1$, so all 2¹⁶
+	/// rows are stored, and the following rows of the factors are off by
+	/// about $\frac{1}{2^{16}}$.
+	pub fn lu_decompose(&self) -> ToeplitzLu<E, L, U>
+	where
+		E: Real,
+	{
+		self.lu_decompose_capped(MAX_STORED_ROWS)
+	}
+
+	/// Perform LU decomposition of this matrix like [`Self::lu_decompose`],
+	/// but storing at most `max_rows` rows of the factors
+	pub fn lu_decompose_capped(&self, max_rows: usize) -> ToeplitzLu<E, L, U>
+	where
+		E: Real,
+	{
+		assert_ne!(max_rows, 0, "ToeplitzLu needs at least one row");
+
+		let width = L + U + 1;
+		let window = L.max(U) + 1;
+		let mut rows: Vec<E> = Vec::new();
+		let mut row = vec![E::zero(); width];
+		let mut changes: Vec<E> = Vec::new();
+		let mut tail = E::max_value();
+
+		// The `(k, m)`th element of the factors, which is in row `k`'s storage
+		// at `L + m - k`
+		let get = |rows: &[E], k: usize, m: usize| rows[width * (k - 1) + L + m - k];
+
+		for k in 1..=self.n.min(max_rows) {
+			row.fill(E::zero());
+
+			// $l_{k,j} = \frac{a_{k,j} - \sum_{t=\max(1, k - L, j - U)}^{j-1} l_{k,t} u_{t,j}}{u_{j,j}}$ for $j = \max(1, k - L), ..., k - 1$
+			for j in 1.max(k.saturating_sub(L))..k {
+				let sum = (1.max(k.saturating_sub(L)).max(j.saturating_sub(U))..j)
+					.map(|t| row[L + t - k] * get(&rows, t, j))
+					.reduce(Add::add)
+					.unwrap_or_else(E::zero);
+
+				row[L + j - k] = (*self.diagonal(j as isize - k as isize) - sum) / get(&rows, j, j);
+			}
+
+			// $u_{k,m} = a_{k,m} - \sum_{j=\max(1, k - L, m - U)}^{k-1} l_{k,j} u_{j,m}$ for $m = k, ..., k + U$
+			for m in k..=k + U {
+				let sum = (1.max(k.saturating_sub(L)).max(m.saturating_sub(U))..k)
+					.map(|j| row[L + j - k] * get(&rows, j, m))
+					.reduce(Add::add)
+					.unwrap_or_else(E::zero);
+
+				row[L + m - k] = *self.diagonal((m - k) as isize) - sum;
+			}
+
+			// $d_k = \max_m |r_{k,m} - r_{k-1,m}|$
+			if k > 1 {
+				let previous = &rows[rows.len() - width..];
+				changes.push(
+					row.iter()
+						.zip(previous)
+						.map(|(&a, &b)| (a - b).abs())
+						.fold(E::zero(), E::max),
+				);
+			}
+
+			rows.extend_from_slice(&row);
+
+			// Assuming that the rows converge geometrically, $d_{k+1} \approx q d_k$,
+			// with the rate $q = (\frac{d_k}{d_{k-w}})^{1/w}$ measured over the
+			// $w = \max(L, U) + 1$ rows which a row depends on, all of the
+			// following rows change by about $\frac{q}{1 - q} d_k$
+			if changes.len() > window {
+				let d = changes[changes.len() - 1];
+				let q = (d / changes[changes.len() - 1 - window])
+					.powf(E::one() / E::from(window).unwrap());
+
+				tail = if d.is_zero() {
+					E::zero()
+				} else if q < E::one() {
+					d * q / (E::one() - q)
+				} else {
+					E::max_value()
+				};
+
+				let scale = row.iter().fold(E::zero(), |max, e| max.max(e.abs()));
+
+				if tail <= E::epsilon() * scale {
+					break;
+				}
+			}
+		}
+
+		if rows.len() == width * self.n {
+			tail = E::zero();
+		}
+
+		ToeplitzLu {
+			n: self.n,
+			rows,
+			tail,
+		}
+	}
+}
+
+impl<E: Real + FloatConst> ToeplitzBand<E, 1, 1> {
+	/// Get the eigenvalues of this tridiagonal Toeplitz matrix,
+	/// $λ_k = a + 2\sqrt{bc} \cos(\frac{kπ}{n + 1})$ for $k = 1, ..., n$, where
+	/// $a$ is the main diagonal and $b$ and $c$ are the sub- and superdiagonal,
+	/// in descending order if $bc \ge 0$. Returns `None` if $bc < 0$, in which
+	/// case the eigenvalues are complex.
+	pub fn eigenvalues(&self) -> Option<Vector<E>> {
+		let (b, a, c) = (self.diagonals[0], self.diagonals[1], self.diagonals[2]);
+		let bc = b * c;
+
+		if bc < E::zero() {
+			return None;
+		}
+
+		let two = E::one() + E::one();
+		let n = E::from(self.n + 1).unwrap();
+
+		Some(Vector::from_iter((1..=self.n).map(|k| {
+			a + two * bc.sqrt() * (E::from(k).unwrap() * E::PI() / n).cos()
+		})))
+	}
+}
+
+impl<E, const L: usize, const U: usize> ToeplitzLu<E, L, U> {
+	/// Get the number of distinct stored rows of the factors
+	pub fn stored_rows(&self) -> usize {
+		self.rows.len() / (L + U + 1)
+	}
+
+	/// Get an estimate of how much the elements of the rows of the factors
+	/// after the last stored row differ from it, which is 0 if all rows are
+	/// stored. The estimate assumes that the rows converge geometrically, so
+	/// it is too low (about half of the actual error) for rows which converge
+	/// like $\frac{1}{k}$.
+	pub fn tail_estimate(&self) -> &E {
+		&self.tail
+	}
+
+	/// Get the storage of row `k` of the factors
+	fn row(&self, k: usize) -> &[E] {
+		let width = L + U + 1;
+		&self.rows[width * (k - 1).min(self.stored_rows() - 1)..][..width]
+	}
+
+	/// Get the `(i, j)`th index of the lower matrix (without the implied unit
+	/// diagonal), with `j < i <= j + L`
+	fn l(&self, i: usize, j: usize) -> &E {
+		&self.row(i)[L + j - i]
+	}
+
+	/// Get the `(i, j)`th index of the upper matrix, with `i <= j <= i + U`
+	fn u(&self, i: usize, j: usize) -> &E {
+		&self.row(i)[L + j - i]
+	}
+}
+
+impl<E: Num + Copy, const L: usize, const U: usize> ToeplitzLu<E, L, U> {
+	pub fn det(&self) -> E {
+		let mut res = E::one();
+
+		for i in 1..=self.n {
+			res = res * *self.u(i, i);
+		}
+
+		res
+	}
+
+	pub fn solve(&self, b: &Vector<E>) -> Vector<E> {
+		assert_eq!(
+			self.n,
+			b.n(),
+			"Can't solve system of equations for a matrix and vector with different heights"
+		);
+
+		let mut x = b.clone();
+
+		// $Ly = b$
+		for m in 1..=self.n {
+			x[m] = x[m]
+				- (1.max(m.saturating_sub(L))..=m - 1)
+					.map(|i| *self.l(m, i) * x[i])
+					.reduce(Add::add)
+					.unwrap_or_else(E::zero);
+		}
+
+		// $Ux = y$
+		for m in (1..=self.n).rev() {
+			x[m] = (x[m]
+				- (m + 1..=self.n.min(m + U))
+					.map(|i| *self.u(m, i) * x[i])
+					.reduce(Add::add)
+					.unwrap_or_else(E::zero))
+				/ *self.u(m, m);
+		}
+
+		x
+	}
+}
+
+impl<E: Debug, const L: usize, const U: usize> Debug for ToeplitzBand<E, L, U> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.debug_struct(&format!(
+			"ToeplitzBand<{}, L = {L}, U = {U}>",
+			any::type_name::<E>()
+		))
+		.field("n", &self.n)
+		.field("diagonals", &self.diagonals)
+		.finish()
+	}
+}
+
+impl<E: Debug, const L: usize, const U: usize> Debug for ToeplitzLu<E, L, U> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		f.debug_struct(&format!(
+			"ToeplitzLu<{}, L = {L}, U = {U}>",
+			any::type_name::<E>()
+		))
+		.field("n", &self.n)
+		.field("rows", &self.rows)
+		.field("tail", &self.tail)
+		.finish()
+	}
+}
+
+impl<E, const L: usize, const U: usize> Index<(usize, usize)> for ToeplitzBand<E, L, U> {
+	type Output = E;
+
+	fn index(&self, index: (usize, usize)) -> &Self::Output {
+		let (i, j) = index;
+
+		assert!(
+			i != 0 && j != 0,
+			"Index {index:?} out of bounds: matrices use 1-based indexing"
+		);
+		assert!(
+			i <= self.n && j <= self.n,
+			"Index {index:?} out of bounds: matrix is {}x{}",
+			self.n,
+			self.n
+		);
+
+		if (i < j && j - i > U) || (i > j && i - j > L) {
+			&self.zero
+		} else {
+			&self.diagonals[L + j - i]
+		}
+	}
+}
+
+impl<E: Num + Copy, const L: usize, const U: usize> Mul<&Vector<E>> for &ToeplitzBand<E, L, U> {
+	type Output = Vector<E>;
+
+	fn mul(self, rhs: &Vector<E>) -> Self::Output {
+		assert_eq!(
+			self.n,
+			rhs.n(),
+			"Can't multiply a matrix and vector with different sizes"
+		);
+
+		// $(Ax)_i = \sum_{j=\max(1, i - L)}^{\min(n, i + U)} a_{j - i} x_j$
+		Vector::from_iter((1..=self.n).map(|i| {
+			(1.max(i.saturating_sub(L))..=(i + U).min(self.n))
+				.map(|j| self.diagonals[L + j - i] * rhs[j])
+				.reduce(Add::add)
+				.unwrap_or_else(E::zero)
+		}))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn matches_band_matrix() {
+		let toeplitz = crate::test_matrices::num5_toeplitz(200);
+		let band = crate::test_matrices::num5_matrix(200);
+		let b = crate::test_matrices::num5_b(200);
+
+		assert!(toeplitz.to_band() == band);
+		assert!((&(&toeplitz * &b) - &(&band * &b)).norm() == 0.0);
+
+		let lu = toeplitz.lu_decompose();
+		let exact = band.lu_decompose().solve(&b);
+
+		assert!(lu.stored_rows() < 50);
+		assert!((&lu.solve(&b) - &exact).norm() < 1e-10);
+	}
+
+	#[test]
+	fn num5_stored_rows() {
+		// The factors of the NUM5 matrix converge long before the last row
+		let lu = crate::test_matrices::num5_toeplitz(1_000_000).lu_decompose();
+		assert!(lu.stored_rows() < 50);
+		assert!(*lu.tail_estimate() < 1e-15);
+
+		let small = crate::test_matrices::num5_toeplitz(10).lu_decompose();
+		assert!(small.stored_rows() <= 10);
+		let b = crate::test_matrices::num5_b(10);
+		let exact = crate::test_matrices::num5_matrix(10)
+			.lu_decompose()
+			.solve(&b);
+		assert!((&small.solve(&b) - &exact).norm() < 1e-12);
+	}
+
+	#[test]
+	fn slowly_converging_rows() {
+		// $u_{k,k} = \frac{k + 1}{k}$, so the rows never stop changing
+		let a = ToeplitzBand::<f64, 1, 1>::new(1 << 40, &[-1.0, 2.0, -1.0]).unwrap();
+		let lu = a.lu_decompose_capped(1000);
+		assert_eq!(lu.stored_rows(), 1000);
+
+		// The last row is $\frac{1}{1000}$ away from the limit
+		assert!((lu.u(1000, 1000) - 1001.0 / 1000.0).abs() < 1e-12);
+		assert!(*lu.tail_estimate() > 1e-4 && *lu.tail_estimate() < 1e-3);
+
+		let lu = a.lu_decompose();
+		assert_eq!(lu.stored_rows(), MAX_STORED_ROWS);
+	}
+
+	#[test]
+	fn new() {
+		assert_eq!(
+			ToeplitzBand::<f64, 1, 1>::new(10, &[1.0, 2.0]).err(),
+			Some(BandMatrixError::DiagonalCount {
+				expected: 3,
+				found: 2
+			})
+		);
+	}
+
+	#[test]
+	fn large_toeplitz_solve() {
+		let n = 1_000_000;
+		let a = ToeplitzBand::<f64, 1, 1>::new(n, &[-1.0, 4.0, -1.0]).unwrap();
+		let x = Vector::from_iter((1..=n).map(|i| (i % 10) as f64));
+		let b = &a * &x;
+
+		let lu = a.lu_decompose();
+		assert!(lu.stored_rows() < 100);
+		assert!((&lu.solve(&b) - &x).norm() < 1e-8);
+	}
+
+	#[test]
+	fn tridiagonal_eigenvalues() {
+		let a = ToeplitzBand::<f64, 1, 1>::new(10, &[0.5, 3.0, 2.0]).unwrap();
+		let eigenvalues = a.eigenvalues().unwrap();
+
+		// The determinant is the product of the eigenvalues
+		let product = eigenvalues.iter().product::<f64>();
+		let det = a.lu_decompose().det();
+		assert!((product - det).abs() < 1e-9 * det.abs());
+
+		assert!(ToeplitzBand::<f64, 1, 1>::new(10, &[-0.5, 3.0, 2.0])
+			.unwrap()
+			.eigenvalues()
+			.is_none());
+	}
+}
@@ -0,0 +1,518 @@
+use alloc::{boxed::Box, format, vec};
+use core::slice;
+use core::{
+	any,
+	fmt::{Debug, Display, Formatter, Result as FmtResult},
+	iter,
+	marker::PhantomData,
+	ops::{
+		Add, AddAssign, Deref, DerefMut, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub,
+		SubAssign,
+	},
+};
+
+use num_traits::{real::Real, Num};
+#[cfg(feature = "rand")]
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+
+/// A column vector, which owns its elements by default, but can also borrow
+/// them from any slice (see [`VectorView`] and [`VectorViewMut`]).
+///
+/// Indexing a vector is 1-based (`x[1]` is the first element). It also
+/// dereferences to a slice of its elements for the slice methods, but
+/// `x[i]` still uses the vector's own 1-based [`Index`], which takes priority
+/// over the slice's. The 0-based accessors are explicit: [`Vector::at0`],
+/// [`Vector::as_slice`] and [`Vector::as_mut_slice`].
+#[derive(Clone, PartialEq)]
+pub struct Vector<E, S = Box<[E]>> {
+	elements: S,
+	_marker: PhantomData<E>,
+}
+
+/// A column vector borrowing its elements from a slice
+pub type VectorView<'a, E> = Vector<E, &'a [E]>;
+
+/// A column vector mutably borrowing its elements from a slice
+pub type VectorViewMut<'a, E> = Vector<E, &'a mut [E]>;
+
+impl<E, S: Deref<Target = [E]>> Vector<E, S> {
+	/// Create a vector over the given elements
+	pub fn from_elements(elements: S) -> Self {
+		Self {
+			elements,
+			_marker: PhantomData,
+		}
+	}
+
+	pub fn n(&self) -> usize {
+		self.elements.len()
+	}
+
+	/// Get the element at the 0-based `index`, like `self[index + 1]`
+	pub fn at0(&self, index: usize) -> &E {
+		&self.elements[index]
+	}
+
+	/// Get the element at the 1-based `index`, the same as `self[index]`
+	pub fn at1(&self, index: usize) -> &E {
+		&self[index]
+	}
+
+	/// Get this vector's elements as a slice, which (unlike the vector)
+	/// is indexed from 0
+	pub fn as_slice(&self) -> &[E] {
+		&self.elements
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = &E> {
+		self.elements.iter()
+	}
+
+	/// Borrow this vector's elements
+	pub fn view(&self) -> VectorView<'_, E> {
+		Vector::from_elements(&self.elements)
+	}
+}
+
+impl<E, S: DerefMut<Target = [E]>> Vector<E, S> {
+	pub fn iter_mut(&mut self) -> slice::IterMut<'_, E> {
+		self.elements.iter_mut()
+	}
+
+	/// Mutably get the element at the 0-based `index`, like
+	/// `&mut self[index + 1]`
+	pub fn at0_mut(&mut self, index: usize) -> &mut E {
+		&mut self.elements[index]
+	}
+
+	/// Mutably get the element at the 1-based `index`, the same as
+	/// `&mut self[index]`
+	pub fn at1_mut(&mut self, index: usize) -> &mut E {
+		&mut self[index]
+	}
+
+	/// Get this vector's elements as a mutable slice, which (unlike the
+	/// vector) is indexed from 0
+	pub fn as_mut_slice(&mut self) -> &mut [E] {
+		&mut self.elements
+	}
+
+	/// Mutably borrow this vector's elements
+	pub fn view_mut(&mut self) -> VectorViewMut<'_, E> {
+		Vector::from_elements(&mut self.elements)
+	}
+}
+
+impl<E: Num> Vector<E> {
+	/// Create a new zeroed vector of length n
+	pub fn new(n: usize) -> Self {
+		Self::from_iter(iter::repeat_with(E::zero).take(n))
+	}
+}
+
+#[cfg(feature = "rand")]
+impl<E> Vector<E>
+where
+	Standard: Distribution<E>,
+{
+	/// Create a vector of length n with random elements from the thread-local
+	/// random number generator
+	pub fn random(n: usize) -> Self {
+		Self::random_with(n, &mut rand::thread_rng())
+	}
+
+	/// Create a vector of length n with random elements from `rng`
+	pub fn random_with<R: Rng + ?Sized>(n: usize, rng: &mut R) -> Self {
+		Self::from_iter(iter::repeat_with(|| rng.gen()).take(n))
+	}
+}
+
+impl<E: Num + Copy, S: Deref<Target = [E]>> Vector<E, S> {
+	/// Get the dot product of this vector and `rhs`
+	pub fn dot<T: Deref<Target = [E]>>(&self, rhs: &Vector<E, T>) -> E {
+		assert_eq!(
+			self.n(),
+			rhs.n(),
+			"Can't compute the dot product of vectors with different sizes"
+		);
+
+		self.elements
+			.iter()
+			.zip(rhs.iter())
+			.map(|(&a, &b)| a * b)
+			.reduce(Add::add)
+			.unwrap_or_else(E::zero)
+	}
+}
+
+impl<E: Num + Copy, S: DerefMut<Target = [E]>> Vector<E, S> {
+	/// $y = ax + y$, where $y$ is this vector
+	pub fn axpy<T: Deref<Target = [E]>>(&mut self, a: E, x: &Vector<E, T>) {
+		assert_eq!(
+			self.n(),
+			x.n(),
+			"Can't compute axpy for vectors with different sizes"
+		);
+
+		for (y, &x) in self.elements.iter_mut().zip(x.iter()) {
+			*y = *y + a * x;
+		}
+	}
+
+	/// Multiply every element by `a`
+	pub fn scale(&mut self, a: E) {
+		*self *= a;
+	}
+
+	/// Set every element to `value`
+	pub fn fill(&mut self, value: E) {
+		self.elements.fill(value);
+	}
+}
+
+impl<E: Copy, S: Deref<Target = [E]>> Vector<E, S> {
+	/// Apply `f` to every element, collecting the results into a new vector
+	pub fn map<R>(&self, f: impl FnMut(E) -> R) -> Vector<R> {
+		self.iter().copied().map(f).collect()
+	}
+
+	/// Apply `f` to every pair of elements of this vector and `rhs`,
+	/// collecting the results into a new vector
+	pub fn zip_map<R, T: Deref<Target = [E]>>(
+		&self,
+		rhs: &Vector<E, T>,
+		mut f: impl FnMut(E, E) -> R,
+	) -> Vector<R> {
+		assert_eq!(self.n(), rhs.n(), "Can't zip vectors with different sizes");
+
+		self.iter().zip(rhs).map(|(&a, &b)| f(a, b)).collect()
+	}
+}
+
+impl<E: Real, S: Deref<Target = [E]>> Vector<E, S> {
+	/// Get the euclidean norm of the vector
+	pub fn norm(&self) -> E {
+		self.elements
+			.iter()
+			.map(|&e| e * e)
+			.reduce(Add::add)
+			.unwrap_or_else(E::zero)
+			.sqrt()
+	}
+
+	/// Get the 1-norm $\sum_i |x_i|$ of the vector
+	pub fn norm_1(&self) -> E {
+		self.elements
+			.iter()
+			.map(|e| e.abs())
+			.reduce(Add::add)
+			.unwrap_or_else(E::zero)
+	}
+
+	/// Get the maximum norm $\max_i |x_i|$ of the vector
+	pub fn norm_inf(&self) -> E {
+		self.elements
+			.iter()
+			.fold(E::zero(), |max, e| max.max(e.abs()))
+	}
+
+	/// Get the p-norm $(\sum_i |x_i|^p)^{1/p}$ of the vector
+	pub fn norm_p(&self, p: E) -> E {
+		self.elements
+			.iter()
+			.map(|e| e.abs().powf(p))
+			.reduce(Add::add)
+			.unwrap_or_else(E::zero)
+			.powf(p.recip())
+	}
+}
+
+impl<E> FromIterator<E> for Vector<E> {
+	fn from_iter<T: IntoIterator<Item = E>>(iter: T) -> Self {
+		Self::from_elements(iter.into_iter().collect())
+	}
+}
+
+impl<E: Debug, S: Deref<Target = [E]>> Debug for Vector<E, S> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		if f.alternate() {
+			f.debug_struct(&format!("Vector<{}>", any::type_name::<E>()))
+				.field("elements", &&*self.elements)
+				.finish()
+		} else {
+			f.debug_list().entries(self.elements.iter()).finish()
+		}
+	}
+}
+
+impl<E: Display, S: Deref<Target = [E]>> Display for Vector<E, S> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		let width = f.width().unwrap_or(1);
+		let precision = f.precision().unwrap_or(6);
+
+		write!(f, "[ ")?;
+
+		for e in self.elements.iter() {
+			write!(
+				f,
+				"{e:width$.precision$} ",
+				width = width,
+				precision = precision
+			)?;
+		}
+
+		write!(f, "]ᵀ")
+	}
+}
+
+impl<E, S: Deref<Target = [E]>> Deref for Vector<E, S> {
+	type Target = [E];
+
+	fn deref(&self) -> &Self::Target {
+		&self.elements
+	}
+}
+
+impl<E, S: DerefMut<Target = [E]>> DerefMut for Vector<E, S> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.elements
+	}
+}
+
+impl<E, S: Deref<Target = [E]>> Index<usize> for Vector<E, S> {
+	type Output = E;
+
+	fn index(&self, index: usize) -> &Self::Output {
+		assert_ne!(
+			index, 0,
+			"Index out of bounds: Vectors use 1-based indexing"
+		);
+
+		&self.elements[index - 1]
+	}
+}
+
+impl<E, S: DerefMut<Target = [E]>> IndexMut<usize> for Vector<E, S> {
+	fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+		assert_ne!(
+			index, 0,
+			"Index out of bounds: Vectors use 1-based indexing"
+		);
+
+		&mut self.elements[index - 1]
+	}
+}
+
+impl<'a, E, S: Deref<Target = [E]>> IntoIterator for &'a Vector<E, S> {
+	type Item = &'a E;
+	type IntoIter = slice::Iter<'a, E>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.elements.iter()
+	}
+}
+
+impl<E> IntoIterator for Vector<E> {
+	type Item = E;
+	type IntoIter = vec::IntoIter<E>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.elements.into_vec().into_iter()
+	}
+}
+
+/// Implement the element-wise operator `$op` for all combinations of owned and
+/// borrowed vectors, reusing the storage of an owned operand for the result
+macro_rules! impl_elementwise_op {
+	($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt, $verb:literal) => {
+		impl<E: Num + Copy, S: DerefMut<Target = [E]>, T: Deref<Target = [E]>>
+			$assign_trait<&Vector<E, T>> for Vector<E, S>
+		{
+			fn $assign_method(&mut self, rhs: &Vector<E, T>) {
+				assert_eq!(
+					self.n(),
+					rhs.n(),
+					concat!("Can't ", $verb, " vectors with different sizes")
+				);
+
+				for (a, &b) in self.elements.iter_mut().zip(rhs.iter()) {
+					*a = *a $op b;
+				}
+			}
+		}
+
+		impl<E: Num + Copy, S: DerefMut<Target = [E]>, T: Deref<Target = [E]>>
+			$assign_trait<Vector<E, T>> for Vector<E, S>
+		{
+			fn $assign_method(&mut self, rhs: Vector<E, T>) {
+				self.$assign_method(&rhs);
+			}
+		}
+
+		impl<E: Num + Copy, S: Deref<Target = [E]>, T: Deref<Target = [E]>> $trait<&Vector<E, T>>
+			for &Vector<E, S>
+		{
+			type Output = Vector<E>;
+
+			fn $method(self, rhs: &Vector<E, T>) -> Self::Output {
+				let mut res = Vector::from_iter(self.iter().copied());
+				res.$assign_method(rhs);
+				res
+			}
+		}
+
+		impl<E: Num + Copy, T: Deref<Target = [E]>> $trait<&Vector<E, T>> for Vector<E> {
+			type Output = Vector<E>;
+
+			fn $method(mut self, rhs: &Vector<E, T>) -> Self::Output {
+				self.$assign_method(rhs);
+				self
+			}
+		}
+
+		impl<E: Num + Copy, T: Deref<Target = [E]>> $trait<Vector<E, T>> for Vector<E> {
+			type Output = Vector<E>;
+
+			fn $method(mut self, rhs: Vector<E, T>) -> Self::Output {
+				self.$assign_method(&rhs);
+				self
+			}
+		}
+
+		impl<E: Num + Copy, S: Deref<Target = [E]>> $trait<Vector<E>> for &Vector<E, S> {
+			type Output = Vector<E>;
+
+			fn $method(self, mut rhs: Vector<E>) -> Self::Output {
+				assert_eq!(
+					self.n(),
+					rhs.n(),
+					concat!("Can't ", $verb, " vectors with different sizes")
+				);
+
+				for (b, &a) in rhs.elements.iter_mut().zip(self.iter()) {
+					*b = a $op *b;
+				}
+
+				rhs
+			}
+		}
+	};
+}
+
+impl_elementwise_op!(Add, add, AddAssign, add_assign, +, "add");
+impl_elementwise_op!(Sub, sub, SubAssign, sub_assign, -, "subtract");
+
+/// Implement the operator `$op` with a scalar for owned and borrowed vectors
+macro_rules! impl_scalar_op {
+	($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+		impl<E: Num + Copy, S: DerefMut<Target = [E]>> $assign_trait<E> for Vector<E, S> {
+			fn $assign_method(&mut self, rhs: E) {
+				for e in self.elements.iter_mut() {
+					*e = *e $op rhs;
+				}
+			}
+		}
+
+		impl<E: Num + Copy> $trait<E> for Vector<E> {
+			type Output = Vector<E>;
+
+			fn $method(mut self, rhs: E) -> Self::Output {
+				self.$assign_method(rhs);
+				self
+			}
+		}
+
+		impl<E: Num + Copy, S: Deref<Target = [E]>> $trait<E> for &Vector<E, S> {
+			type Output = Vector<E>;
+
+			fn $method(self, rhs: E) -> Self::Output {
+				self.iter().map(|&e| e $op rhs).collect()
+			}
+		}
+	};
+}
+
+impl_scalar_op!(Mul, mul, MulAssign, mul_assign, *);
+impl_scalar_op!(Div, div, DivAssign, div_assign, /);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn elementwise_ops() {
+		let x = Vector::from_iter([1.0, 2.0, 3.0]);
+		let elements = [0.5, -1.0, 4.0];
+		let y = VectorView::from_elements(&elements[..]);
+		let sum = Vector::from_iter([1.5, 1.0, 7.0]);
+		let difference = Vector::from_iter([0.5, 3.0, -1.0]);
+
+		assert_eq!(&x + &y, sum);
+		assert_eq!(x.clone() + &y, sum);
+		assert_eq!(x.clone() + y.clone(), sum);
+		assert_eq!(&y + x.clone(), sum);
+		assert_eq!(&x - &y, difference);
+		assert_eq!(x.clone() - y.clone(), difference);
+		assert_eq!(&y - x.clone(), difference.map(|e| -e));
+
+		let mut z = x.clone();
+		z += &y;
+		z -= y;
+		assert_eq!(z, x);
+
+		assert_eq!(&x * 2.0, Vector::from_iter([2.0, 4.0, 6.0]));
+		assert_eq!(x.clone() / 2.0, Vector::from_iter([0.5, 1.0, 1.5]));
+
+		let mut buffer = [1.0, 1.0, 1.0];
+		let mut view = VectorViewMut::from_elements(&mut buffer[..]);
+		view *= 3.0;
+		view += &x;
+		assert_eq!(buffer, [4.0, 5.0, 6.0]);
+	}
+
+	#[test]
+	fn blas() {
+		let mut y = Vector::from_iter([1.0, -2.0, 2.0]);
+		let x = Vector::from_iter([3.0, 0.0, -4.0]);
+
+		assert_eq!(x.dot(&y), -5.0);
+		assert_eq!(x.norm(), 5.0);
+		assert_eq!(x.norm_1(), 7.0);
+		assert_eq!(x.norm_inf(), 4.0);
+		assert!((x.norm_p(2.0) - x.norm()).abs() < 1e-15);
+		assert!((x.norm_p(1.0) - x.norm_1()).abs() < 1e-15);
+
+		y.axpy(2.0, &x);
+		assert_eq!(y, Vector::from_iter([7.0, -2.0, -6.0]));
+
+		y.scale(0.5);
+		assert_eq!(y.as_slice(), &[3.5, -1.0, -3.0]);
+		assert_eq!(y.len(), 3);
+		assert_eq!(y[1], 3.5);
+		// Slice methods are 0-based, but indexing stays 1-based
+		assert_eq!(y.first(), Some(&3.5));
+		assert_eq!(y.iter().position(|&e| e == -1.0), Some(1));
+		assert_eq!(y[2], -1.0);
+		assert_eq!(
+			y.zip_map(&x, |a, b| a * b),
+			Vector::from_iter([10.5, 0.0, 12.0])
+		);
+
+		y.fill(0.0);
+		assert_eq!(y, Vector::new(3));
+	}
+
+	#[test]
+	fn zero_based_indexing() {
+		let mut x = Vector::from_iter([1.0, 2.0, 3.0]);
+
+		assert_eq!(x.at0(0), &1.0);
+		assert_eq!(x.at1(1), &1.0);
+		assert_eq!(x.at0(2), x.at1(3));
+
+		*x.at0_mut(1) = 5.0;
+		assert_eq!(x[2], 5.0);
+		*x.at1_mut(3) = 6.0;
+		assert_eq!(*x.at0(2), 6.0);
+	}
+}
@@ -0,0 +1,123 @@
+use core::iter;
+
+use alloc::vec::Vec;
+use num_traits::Signed;
+
+use crate::{dense, matrix::BandMatrix, parallel::MaybeSync, simd::MaybeSimd, vector::Vector};
+
+/// Solve $(A' + UV^T)x = b$ for $x$, where the `k` columns of $U$ and $V$ are
+/// given by `u_cols` and `v_cols`, using the Woodbury identity
+/// $(A' + UV^T)^{-1} = A'^{-1} - A'^{-1}U(I + V^TA'^{-1}U)^{-1}V^TA'^{-1}$.
+/// Fails if the LU decomposition of $A'$ has a zero pivot or if the
+/// capacitance matrix $I + V^TA'^{-1}U$ is singular, in which case the identity
+/// doesn't apply.
+pub fn solve_low_rank<
+	E: Signed + PartialOrd + Copy + MaybeSync + MaybeSimd,
+	const L: usize,
+	const U: usize,
+>(
+	a_prime: BandMatrix<E, L, U>,
+	u_cols: &[Vector<E>],
+	v_cols: &[Vector<E>],
+	b: Vector<E>,
+) -> Option<Vector<E>> {
+	assert_eq!(a_prime.n(), b.n(), "A''s height must equal b's size");
+	assert_eq!(
+		u_cols.len(),
+		v_cols.len(),
+		"U and V must have the same number of columns"
+	);
+
+	for col in u_cols.iter().chain(v_cols) {
+		assert_eq!(a_prime.n(), col.n(), "A''s height must equal U's and V's");
+	}
+
+	// $A' = LU$
+	let lu = a_prime.lu_decompose();
+
+	if (1..=b.n()).any(|k| lu[(k, k)].is_zero()) {
+		return None;
+	}
+
+	// $LUy = b$, $LUZ = U$
+	let mut rhs: Vec<_> = iter::once(b).chain(u_cols.iter().cloned()).collect();
+	lu.solve_many_in_place(&mut rhs);
+	let z = rhs.split_off(1);
+	let y = rhs.pop().unwrap();
+
+	// $C = I + V^TZ$
+	let capacitance = v_cols
+		.iter()
+		.enumerate()
+		.map(|(i, v)| {
+			z.iter()
+				.enumerate()
+				.map(|(j, z)| if i == j { E::one() } else { E::zero() } + v.dot(z))
+				.collect()
+		})
+		.collect();
+
+	// $Cw = V^Ty$
+	let w = dense::solve(capacitance, v_cols.iter().map(|v| v.dot(&y)).collect())?;
+
+	// $x = y - Zw$
+	Some(z.into_iter().zip(w).fold(y, |x, (z, w)| {
+		x - Vector::from_iter(z.into_iter().map(|z| z * w))
+	}))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_matrices::{num4_b, num4_b_nalgebra, num4_matrix, num4_matrix_nalgebra};
+
+	#[test]
+	fn low_rank_matches_nalgebra() {
+		let n = 50;
+		let u_cols: Vec<_> = (1..=3)
+			.map(|k| Vector::from_iter((1..=n).map(|i| ((i * k) % 7) as f64 / 7.0)))
+			.collect();
+		let v_cols: Vec<_> = (1..=3)
+			.map(|k| Vector::from_iter((1..=n).map(|i| if i % (k + 1) == 0 { 1.0 } else { 0.5 })))
+			.collect();
+
+		let mut nal = num4_matrix_nalgebra(n).add_scalar(-1.0);
+
+		for (u, v) in u_cols.iter().zip(&v_cols) {
+			for i in 1..=n {
+				for j in 1..=n {
+					nal[(i - 1, j - 1)] += u[i] * v[j];
+				}
+			}
+		}
+
+		let x = solve_low_rank(num4_matrix(n), &u_cols, &v_cols, num4_b(n)).unwrap();
+		let xnal = nal.lu().solve(&num4_b_nalgebra(n)).unwrap();
+
+		for i in 1..=n {
+			assert!((x[i] - xnal[i - 1]).abs() < 1e-12);
+		}
+	}
+
+	#[test]
+	fn singular() {
+		let n = 50;
+		let identity =
+			|| BandMatrix::<f64, 1, 1>::from_constant_diagonals(n, &[0.0, 1.0, 0.0]).unwrap();
+		let u = [Vector::from_iter((1..=n).map(|i| {
+			if i == 1 {
+				1.0
+			} else {
+				0.0
+			}
+		}))];
+		let minus_u = [&u[0] * -1.0];
+
+		// $I - e_1 e_1^T$ is singular, as is the capacitance matrix $1 - 1$
+		assert!(solve_low_rank(identity(), &u, &minus_u, num4_b(n)).is_none());
+		assert!(solve_low_rank(identity(), &u, &u, num4_b(n)).is_some());
+
+		let a_prime = BandMatrix::<f64, 1, 1>::new(n);
+		assert!(solve_low_rank(a_prime, &u, &u, num4_b(n)).is_none());
+	}
+}